@@ -19,4 +19,49 @@ pub enum Direction {
     TopRight,
     Left,
     Right
+}
+
+impl Direction {
+    /// The side-or-corner keywords of the direction, as used by `to <side-or-corner>`.
+    pub const fn keywords(&self) -> &'static str {
+        match self {
+            Self::Bottom => "bottom",
+            Self::BottomLeft => "bottom left",
+            Self::BottomRight => "bottom right",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Top => "top",
+            Self::TopLeft => "top left",
+            Self::TopRight => "top right"
+        }
+    }
+
+    /// The direction with its left and right components exchanged; `Top` and
+    /// `Bottom` are their own mirror images.
+    pub const fn flip_inline(&self) -> Self {
+        match self {
+            Self::Bottom => Self::Bottom,
+            Self::BottomLeft => Self::BottomRight,
+            Self::BottomRight => Self::BottomLeft,
+            Self::Top => Self::Top,
+            Self::TopLeft => Self::TopRight,
+            Self::TopRight => Self::TopLeft,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left
+        }
+    }
+
+    /// The direction as a clockwise bearing from the top, in degrees.
+    pub const fn deg(&self) -> u16 {
+        match self {
+            Self::Top => 0,
+            Self::TopRight => 45,
+            Self::Right => 90,
+            Self::BottomRight => 135,
+            Self::Bottom => 180,
+            Self::BottomLeft => 225,
+            Self::Left => 270,
+            Self::TopLeft => 315
+        }
+    }
 }
\ No newline at end of file
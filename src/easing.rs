@@ -1,3 +1,9 @@
+pub type Easing = fn(f32, f32, f32, f32) -> f32;
+
+pub fn linear(p: f32, s: f32, c: f32, d: f32) -> f32 {
+    c * (p / d) + s
+}
+
 pub fn ease_in(p: f32, s: f32, c: f32, d: f32) -> f32 {
     c *
     (p / d) *
@@ -0,0 +1,265 @@
+use super::*;
+use ::std::collections::HashMap;
+
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    MissingWindow,
+    MissingDocument,
+    MissingRegistry,
+    FontFaceConstructionFailure(::wasm_bindgen::JsValue),
+    FontFaceSetAdditionFailure(::wasm_bindgen::JsValue),
+    FontFaceLoadFailure(::wasm_bindgen::JsValue)
+}
+
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Style {
+    #[default]
+    Normal,
+    Italic,
+    Oblique
+}
+
+/// How a face is displayed while it loads, through `font-display`.
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Display {
+    Auto,
+    Block,
+    #[default]
+    Swap,
+    Fallback,
+    Optional
+}
+
+/// A font face declaration, the equivalent of an `@font-face` rule.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Face {
+    pub family: String,
+    pub src: Url,
+    pub weight: (u16, u16),
+    pub style: Style,
+    pub unicode_range: Option<String>,
+    pub display: Display
+}
+
+impl Face {
+    pub fn new(family: &str, src: Url) -> Self {
+        Self {
+            family: family.to_owned(),
+            src,
+            weight: (400, 400),
+            style: Style::default(),
+            unicode_range: None,
+            display: Display::default()
+        }
+    }
+
+    pub fn weight(mut self, weight: u16) -> Self {
+        self.weight = (weight, weight);
+        self
+    }
+
+    /// The range of weights covered by a variable face.
+    pub fn weight_range(mut self, min: u16, max: u16) -> Self {
+        self.weight = (min, max);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn unicode_range(mut self, unicode_range: &str) -> Self {
+        self.unicode_range = Some(unicode_range.to_owned());
+        self
+    }
+
+    pub fn display(mut self, display: Display) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Adds the face to `document.fonts` and waits for it to load.
+    pub async fn load(&self) -> Result<()> {
+        let win: ::web_sys::Window = ::web_sys::window().ok_or(Error::MissingWindow)?;
+        let doc: ::web_sys::Document = win.document().ok_or(Error::MissingDocument)?;
+        let descriptors: ::web_sys::FontFaceDescriptors = ::web_sys::FontFaceDescriptors::new();
+        descriptors.set_style(&self.style.to_string());
+        descriptors.set_display(&self.display.to_string());
+        descriptors.set_weight(&if self.weight.0 == self.weight.1 {
+            format!("{}", self.weight.0)
+        } else {
+            format!("{} {}", self.weight.0, self.weight.1)
+        });
+        if let Some(unicode_range) = &self.unicode_range {
+            descriptors.set_unicode_range(unicode_range);
+        }
+        let src: String = format!("url({})", css_string(&self.src.to_string()));
+        let face: ::web_sys::FontFace = ::web_sys::FontFace::new_with_str_and_descriptors(&self.family, &src, &descriptors)
+            .map_err(Error::FontFaceConstructionFailure)?;
+        doc.fonts()
+            .add(&face)
+            .map_err(Error::FontFaceSetAdditionFailure)?;
+        let promise: ::js_sys::Promise = face.load().map_err(Error::FontFaceLoadFailure)?;
+        ::wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map_err(Error::FontFaceLoadFailure)?;
+        Ok(())
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Loading,
+    Loaded,
+    Failed(Error)
+}
+
+/// The load status of every face declared by a `FontRegistry`.
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct Registry(HashMap<String, Vec<Status>>);
+
+impl Registry {
+    /// `None` while any face of `family` is loading, `Some(Ok(true))` once
+    /// all of them have loaded and `Some(Ok(false))` for undeclared families.
+    pub fn loaded(&self, family: &str) -> Option<Result<bool>> {
+        let Some(statuses) = self.0.get(family) else {
+            return Some(Ok(false))
+        };
+        let mut loading: bool = false;
+        for status in statuses {
+            match status {
+                Status::Failed(e) => return Some(Err(e.to_owned())),
+                Status::Loading => loading = true,
+                Status::Loaded => {}
+            }
+        }
+        if loading {
+            return None
+        }
+        Some(Ok(true))
+    }
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct FontRegistryProps {
+    pub faces: Vec<Face>,
+    pub children: Option<Element>
+}
+
+/// Loads `faces` through the `FontFace` API and makes their status
+/// available to `use_font_loaded` below it.
+///
+/// # Example
+/// ```rs
+/// rsx!(
+///     FontRegistry {
+///         faces: vec!(
+///             Face::new("Inter", Url::Internal(asset!("/assets/inter.woff2")))
+///                 .weight_range(100, 900)
+///                 .display(Display::Swap)
+///         ),
+///         App {}
+///     }
+/// )
+/// ```
+#[component]
+pub fn FontRegistry(props: FontRegistryProps) -> Element {
+    let registry: Signal<Registry> = use_context_provider(|| Signal::new(Registry::default()));
+
+    use_effect({
+        let faces: Vec<Face> = props.faces.to_owned();
+        let mut registry: Signal<_> = registry.to_owned();
+        move || {
+            for face in faces.iter() {
+                registry
+                    .write()
+                    .0
+                    .entry(face.family.to_owned())
+                    .or_default()
+                    .push(Status::Loading);
+                let n: usize = registry.peek().0[&face.family].len() - 1;
+                let face: Face = face.to_owned();
+                spawn(async move {
+                    let status: Status = match face.load().await {
+                        Ok(()) => {
+                            measure::clear_measure_cache();
+                            Status::Loaded
+                        },
+                        Err(e) => Status::Failed(e)
+                    };
+                    if let Some(statuses) = registry.write().0.get_mut(&face.family) {
+                        statuses[n] = status;
+                    }
+                });
+            }
+        }
+    });
+
+    rsx!(
+        { props.children }
+    )
+}
+
+/// Whether every face of `family` has loaded, following the loads of the
+/// closest `FontRegistry`. Outside one this is `Some(Err(Error::MissingRegistry))`.
+pub fn use_font_loaded(family: &str) -> Memo<Option<Result<bool>>> {
+    let family: String = family.to_owned();
+    let registry: Option<Signal<Registry>> = try_use_context();
+
+    use_memo(move || {
+        let Some(registry) = registry else {
+            return Some(Err(Error::MissingRegistry))
+        };
+        registry.read().loaded(&family)
+    })
+}
+
+/// Quotes `value` as a CSS string.
+fn css_string(value: &str) -> String {
+    let escaped: String = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\a ");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn quotes_and_escapes_css_strings() {
+        assert_eq!(css_string("/fonts/Inter (v4).woff2"), r#""/fonts/Inter (v4).woff2""#);
+        assert_eq!(css_string(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+}
\ No newline at end of file
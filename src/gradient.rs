@@ -0,0 +1,315 @@
+use super::*;
+
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+pub enum Kind {
+    #[default]
+    #[strum(serialize = "linear-gradient")]
+    Linear,
+    #[strum(serialize = "radial-gradient")]
+    Radial,
+    #[strum(serialize = "conic-gradient")]
+    Conic,
+    #[strum(serialize = "repeating-linear-gradient")]
+    RepeatingLinear,
+    #[strum(serialize = "repeating-radial-gradient")]
+    RepeatingRadial,
+    #[strum(serialize = "repeating-conic-gradient")]
+    RepeatingConic
+}
+
+impl Kind {
+    pub fn repeating(self) -> Self {
+        match self {
+            Self::Linear | Self::RepeatingLinear => Self::RepeatingLinear,
+            Self::Radial | Self::RepeatingRadial => Self::RepeatingRadial,
+            Self::Conic | Self::RepeatingConic => Self::RepeatingConic
+        }
+    }
+}
+
+/// The color space colors are interpolated in between stops.
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ColorSpace {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    Lab,
+    Oklab,
+    Xyz,
+    Hsl,
+    Hwb,
+    Lch,
+    Oklch
+}
+
+/// Which way a gradient runs. Linear gradients run toward a direction or
+/// along an angle, and conic ones start from it. Radial gradients are
+/// centered at a direction's edge or corner; they have no angle, so
+/// `Angle` is not rendered for them.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Orientation {
+    Direction(direction::Direction),
+    Angle(unit::Unit<unit::Angle>)
+}
+
+impl Orientation {
+    /// Swaps left and right. Angles are reflected across the vertical axis,
+    /// so `a` becomes `turn - a`.
+    pub fn flip_inline(&self) -> Self {
+        match self {
+            Self::Direction(direction) => Self::Direction(direction.flip_inline()),
+            Self::Angle(angle) => {
+                let turn: f64 = match angle.measurement {
+                    unit::Angle::Deg => 360.0,
+                    unit::Angle::Grad => 400.0,
+                    unit::Angle::Rad => ::std::f64::consts::TAU,
+                    unit::Angle::Turn => 1.0
+                };
+                Self::Angle(angle.measurement.to_owned().into_unit((turn - angle.n) % turn))
+            }
+        }
+    }
+}
+
+impl From<direction::Direction> for Orientation {
+    fn from(value: direction::Direction) -> Self {
+        Self::Direction(value)
+    }
+}
+
+impl From<unit::Unit<unit::Angle>> for Orientation {
+    fn from(value: unit::Unit<unit::Angle>) -> Self {
+        Self::Angle(value)
+    }
+}
+
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Stop {
+    pub color: color::Color,
+    pub position: Option<unit::Length>
+}
+
+impl From<color::Color> for Stop {
+    fn from(value: color::Color) -> Self {
+        Self {
+            color: value,
+            position: None
+        }
+    }
+}
+
+impl From<(color::Color, unit::Length)> for Stop {
+    fn from(value: (color::Color, unit::Length)) -> Self {
+        Self {
+            color: value.0,
+            position: Some(value.1)
+        }
+    }
+}
+
+impl ::std::fmt::Display for Stop {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match &self.position {
+            Some(position) => write!(f, "{} {}", self.color, position),
+            None => write!(f, "{}", self.color)
+        }
+    }
+}
+
+/// A CSS gradient image, rendered through `Display`.
+///
+/// # Example
+/// ```rs
+/// let gradient = Gradient::linear()
+///     .direction(Direction::BottomRight)
+///     .interpolation(ColorSpace::Oklab)
+///     .stop(Color::from_hex(0x202020))
+///     .stop_at(Color::from_hex(0xffffff), Relative::Percentage.into_unit(80.0));
+/// assert_eq!(
+///     gradient.to_string(),
+///     "linear-gradient(to bottom right in oklab, #202020, #FFFFFF 80%)"
+/// );
+/// ```
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct Gradient {
+    pub kind: Kind,
+    pub orientation: Option<Orientation>,
+    pub stops: Vec<Stop>,
+    pub interpolation: Option<ColorSpace>
+}
+
+impl Gradient {
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            ..Default::default()
+        }
+    }
+
+    pub fn linear() -> Self {
+        Self::new(Kind::Linear)
+    }
+
+    pub fn radial() -> Self {
+        Self::new(Kind::Radial)
+    }
+
+    pub fn conic() -> Self {
+        Self::new(Kind::Conic)
+    }
+
+    pub fn repeating(mut self) -> Self {
+        self.kind = self.kind.repeating();
+        self
+    }
+
+    pub fn direction(mut self, direction: direction::Direction) -> Self {
+        self.orientation = Some(direction.into());
+        self
+    }
+
+    /// Ignored by radial gradients, which have no angle.
+    pub fn angle(mut self, angle: unit::Unit<unit::Angle>) -> Self {
+        self.orientation = Some(angle.into());
+        self
+    }
+
+    pub fn stop(mut self, color: color::Color) -> Self {
+        self.stops.push(color.into());
+        self
+    }
+
+    pub fn stop_at<T>(mut self, color: color::Color, position: T) -> Self
+    where
+        T: Into<unit::Length> {
+        self.stops.push((color, position.into()).into());
+        self
+    }
+
+    pub fn interpolation(mut self, color_space: ColorSpace) -> Self {
+        self.interpolation = Some(color_space);
+        self
+    }
+
+    /// The same gradient with its orientation swapped left for right.
+    pub fn flip_inline(&self) -> Self {
+        Self {
+            orientation: self.orientation
+                .as_ref()
+                .map(|orientation| orientation.flip_inline()),
+            ..self.to_owned()
+        }
+    }
+}
+
+impl From<Vec<color::Color>> for Gradient {
+    fn from(value: Vec<color::Color>) -> Self {
+        Self {
+            stops: value
+                .into_iter()
+                .map(Stop::from)
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl ::std::fmt::Display for Gradient {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let mut prelude: Vec<String> = Vec::new();
+        match (&self.kind, &self.orientation) {
+            (Kind::Linear | Kind::RepeatingLinear, Some(Orientation::Direction(direction))) => {
+                prelude.push(format!("to {}", direction.keywords()));
+            },
+            (Kind::Linear | Kind::RepeatingLinear, Some(Orientation::Angle(angle))) => {
+                prelude.push(format!("{}", angle));
+            },
+            (Kind::Conic | Kind::RepeatingConic, Some(Orientation::Direction(direction))) => {
+                prelude.push(format!("from {}deg", direction.deg()));
+            },
+            (Kind::Conic | Kind::RepeatingConic, Some(Orientation::Angle(angle))) => {
+                prelude.push(format!("from {}", angle));
+            },
+            (Kind::Radial | Kind::RepeatingRadial, Some(Orientation::Direction(direction))) => {
+                prelude.push(format!("at {}", direction.keywords()));
+            },
+            _ => {}
+        };
+        if let Some(color_space) = &self.interpolation {
+            prelude.push(format!("in {}", color_space));
+        }
+        let mut args: Vec<String> = Vec::new();
+        if !prelude.is_empty() {
+            args.push(prelude.join(" "));
+        }
+        args.extend(self.stops.iter().map(|stop| format!("{}", stop)));
+        write!(f, "{}({})", self.kind, args.join(", "))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_css() {
+        let gradient: Gradient = Gradient::linear()
+            .direction(direction::Direction::BottomRight)
+            .interpolation(ColorSpace::Oklab)
+            .stop(color::Color::from_hex(0x202020))
+            .stop_at(color::Color::from_hex(0xffffff), unit::Relative::Percentage.into_unit(80.0));
+        assert_eq!(gradient.to_string(), "linear-gradient(to bottom right in oklab, #202020, #FFFFFF 80%)");
+        let gradient: Gradient = Gradient::conic()
+            .repeating()
+            .angle((unit::Angle::Deg, 45.0).into())
+            .stop(color::Color::from_hex(0x000000))
+            .stop(color::Color::from_hex(0xffffff));
+        assert_eq!(gradient.to_string(), "repeating-conic-gradient(from 45deg, #000000, #FFFFFF)");
+    }
+
+    #[test]
+    fn flipping_reflects_angles_across_the_vertical_axis() {
+        let flip = |measurement: unit::Angle, n: f64| Orientation::Angle(measurement.to_owned().into_unit(n)).flip_inline();
+        assert!(flip(unit::Angle::Deg, 30.0) == Orientation::Angle(unit::Angle::Deg.into_unit(330.0)));
+        assert!(flip(unit::Angle::Deg, 0.0) == Orientation::Angle(unit::Angle::Deg.into_unit(0.0)));
+        assert!(flip(unit::Angle::Turn, 0.25) == Orientation::Angle(unit::Angle::Turn.into_unit(0.75)));
+        assert!(flip(unit::Angle::Grad, 100.0) == Orientation::Angle(unit::Angle::Grad.into_unit(300.0)));
+        assert!(Orientation::Direction(direction::Direction::TopLeft).flip_inline() == Orientation::Direction(direction::Direction::TopRight));
+    }
+
+    #[test]
+    fn radial_gradients_are_centered_by_direction_and_take_absolute_stops() {
+        let gradient: Gradient = Gradient::radial()
+            .direction(direction::Direction::TopRight)
+            .stop(color::Color::from_hex(0x000000))
+            .stop_at(color::Color::from_hex(0xffffff), unit::Absolute::Px.into_unit(120.0));
+        assert_eq!(gradient.to_string(), "radial-gradient(at top right, #000000, #FFFFFF 120px)");
+        let gradient: Gradient = Gradient::radial()
+            .angle((unit::Angle::Deg, 45.0).into())
+            .stop(color::Color::from_hex(0x000000));
+        assert_eq!(gradient.to_string(), "radial-gradient(#000000)");
+    }
+}
\ No newline at end of file
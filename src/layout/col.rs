@@ -2,19 +2,24 @@ use super::*;
 
 #[component]
 pub fn Col(props: CommonProps) -> Element {
+    let style: style::Style = style::Style::default()
+        .set(style::Property::Display, "flex")
+        .set(style::Property::FlexDirection, if props.reverse.unwrap_or_default() {
+            "column-reverse"
+        } else {
+            "column"
+        })
+        .set(style::Property::JustifyContent, props.justify.unwrap_or_default())
+        .set(style::Property::AlignItems, props.align.unwrap_or_default())
+        .set(style::Property::FlexWrap, props.wrap.unwrap_or_default())
+        .maybe(style::Property::Gap, props.gap)
+        .merge(props.style.unwrap_or_default());
+
     rsx!(
         div {
+            id: props.id,
             class: props.class,
-            style: format!(
-                r#"
-                    display: flex;
-                    flex-direction: column;
-                    justify-content: center;
-                    align-items: center;
-                    {}
-                "#,
-                props.style.unwrap_or_default()
-            ),
+            style: style.to_string(),
             { props.children }
         }
     )
@@ -0,0 +1,102 @@
+use super::*;
+
+/// Distribution along the main axis, through `justify-content`.
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Justify {
+    #[strum(serialize = "flex-start")]
+    Start,
+    #[strum(serialize = "flex-end")]
+    End,
+    #[default]
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly
+}
+
+/// Placement along the cross axis, through `align-items`.
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Align {
+    #[strum(serialize = "flex-start")]
+    Start,
+    #[strum(serialize = "flex-end")]
+    End,
+    #[default]
+    Center,
+    Stretch,
+    Baseline
+}
+
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Wrap {
+    #[default]
+    #[strum(serialize = "nowrap")]
+    NoWrap,
+    Wrap,
+    WrapReverse
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct FlexItemProps {
+    pub grow: Option<f64>,
+    pub shrink: Option<f64>,
+    pub basis: Option<unit::Length>,
+    pub id: Option<String>,
+    pub class: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
+    pub children: Option<Element>
+}
+
+/// Sizes a child of a `Col`, `Row` or `Stack` along the main axis.
+#[component]
+pub fn FlexItem(props: FlexItemProps) -> Element {
+    let style: style::Style = style::Style::default()
+        .set(style::Property::FlexGrow, props.grow.unwrap_or(0.0))
+        .set(style::Property::FlexShrink, props.shrink.unwrap_or(1.0))
+        .set(style::Property::FlexBasis, props.basis
+            .map(|basis| basis.to_string())
+            .unwrap_or(String::from("auto")))
+        .merge(props.style.unwrap_or_default());
+
+    rsx!(
+        div {
+            id: props.id,
+            class: props.class,
+            style: style.to_string(),
+            { props.children }
+        }
+    )
+}
\ No newline at end of file
@@ -4,37 +4,80 @@ use super::*;
 #[derive(Clone)]
 #[derive(PartialEq)]
 pub struct GridProps {
-    pub row_count: u8,
-    pub row_gap: Option<String>,
-    pub col_count: u8,
-    pub col_gap: Option<String>,
-    pub gap: Option<String>,
+    pub rows: Option<Vec<Track>>,
+    pub row_gap: Option<responsive::Responsive<String>>,
+    pub cols: Option<Vec<Track>>,
+    pub col_gap: Option<responsive::Responsive<String>>,
+    pub gap: Option<responsive::Responsive<String>>,
+    pub auto_flow: Option<AutoFlow>,
+    /// Named areas that `GridItem`s can be placed into with `area`.
+    pub template_areas: Option<TemplateAreas>,
+    /// Sizes of implicitly created rows.
+    pub auto_rows: Option<Vec<Track>>,
+    /// Sizes of implicitly created columns.
+    pub auto_cols: Option<Vec<Track>>,
     pub class: Option<String>,
-    pub style: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
     pub children: Option<Element>
 }
 
 #[component]
 pub fn Grid(props: GridProps) -> Element {
+    let mut template_areas: Signal<Option<TemplateAreas>> = use_context_provider({
+        let template_areas: Option<TemplateAreas> = props.template_areas.to_owned();
+        move || Signal::new(template_areas)
+    });
+    use_effect(use_reactive((&props.template_areas,), move |(next,)| {
+        if *template_areas.peek() != next {
+            template_areas.set(next);
+        }
+    }));
+    let breakpoints: win::Breakpoints = win::use_breakpoints();
+    let gaps: stylesheet::Scoped = [
+        (style::Property::Gap, props.gap),
+        (style::Property::RowGap, props.row_gap),
+        (style::Property::ColumnGap, props.col_gap)
+    ]
+        .into_iter()
+        .filter_map(|(property, gap)| {
+            gap.map(|gap| gap.scoped(property, &breakpoints))
+        })
+        .fold(stylesheet::Scoped::default(), |gaps, gap| gaps.merge(gap));
+    let class: Option<String> = match gaps.is_empty() {
+        true => props.class,
+        false => {
+            let scoped_class: String = stylesheet::register(&gaps);
+            Some(match props.class {
+                Some(class) => format!("{} {}", scoped_class, class),
+                None => scoped_class
+            })
+        }
+    };
+    let style: style::Style = style::Style::default()
+        .set(style::Property::Display, "grid")
+        .set(style::Property::GridTemplateRows, props.rows
+            .map(|rows| track_list(&rows))
+            .unwrap_or(String::from("none")))
+        .set(style::Property::GridTemplateColumns, props.cols
+            .map(|cols| track_list(&cols))
+            .unwrap_or(String::from("none")))
+        .set(style::Property::GridTemplateAreas, props.template_areas
+            .map(|template_areas| template_areas.to_string())
+            .unwrap_or(String::from("none")))
+        .set(style::Property::GridAutoFlow, props.auto_flow.unwrap_or_default())
+        .set(style::Property::GridAutoRows, props.auto_rows
+            .map(|auto_rows| track_list(&auto_rows))
+            .unwrap_or(String::from("auto")))
+        .set(style::Property::GridAutoColumns, props.auto_cols
+            .map(|auto_cols| track_list(&auto_cols))
+            .unwrap_or(String::from("auto")))
+        .merge(props.style.unwrap_or_default());
+
     rsx!(
         div {
-            style: format!(
-                r#"
-                    display: grid;
-                    grid-template-rows: repeat(1fr, {});
-                    grid-template-columns: repeat(1fr, {});
-                    gap: {};
-                    row-gap: {};
-                    column-gap: {};
-                    {}
-                "#,
-                props.row_count,
-                props.col_count,
-                props.gap.unwrap_or(String::from("0")),
-                props.row_gap.unwrap_or(String::from("0")),
-                props.col_gap.unwrap_or(String::from("0")),
-                props.style.unwrap_or_default()
-            ),
+            class,
+            style: style.to_string(),
             { props.children }
         }
     )
@@ -4,36 +4,57 @@ use super::*;
 #[derive(Clone)]
 #[derive(PartialEq)]
 pub struct GridItemProps {
-    pub x_from: u8,
-    pub y_from: u8,
-    pub x_to: u8,
-    pub y_to: u8,
+    pub x_from: Option<u8>,
+    pub y_from: Option<u8>,
+    pub x_to: Option<u8>,
+    pub y_to: Option<u8>,
+    /// A named area of the parent `Grid`'s `template_areas`, used instead of
+    /// the coordinates.
+    pub area: Option<String>,
     pub z: Option<u128>,
+    pub background_gradient: Option<gradient::Gradient>,
     pub class: Option<String>,
-    pub style: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
     pub children: Option<Element>
 }
 
 #[component]
 pub fn GridItem(props: GridItemProps) -> Element {
+    let template_areas: Option<Signal<Option<TemplateAreas>>> = try_use_context();
+    let known = move |area: &String| -> bool {
+        template_areas
+            .and_then(|template_areas| template_areas.read().as_ref().map(|template_areas| template_areas.contains(area)))
+            .unwrap_or_default()
+    };
+    use_effect(use_reactive((&props.area,), move |(area,)| {
+        if let Some(area) = area.filter(|area| !known(area)) {
+            ::web_sys::console::error_1(&format!("[FAULT]: Unknown grid area `{}`. Placement skipped.", area).into());
+        }
+    }));
+    let area: Option<String> = props.area.filter(known);
+    let placement: style::Style = match area {
+        Some(area) => style::Style::default().set(style::Property::GridArea, area),
+        None => style::Style::default()
+            .set(style::Property::GridColumnStart, line(props.x_from))
+            .set(style::Property::GridColumnEnd, line(props.x_to))
+            .set(style::Property::GridRowStart, line(props.y_from))
+            .set(style::Property::GridRowEnd, line(props.y_to))
+    };
+    let style: style::Style = placement
+        .set(style::Property::ZIndex, props.z.unwrap_or_default())
+        .maybe(style::Property::Background, props.background_gradient)
+        .merge(props.style.unwrap_or_default());
+
     rsx!(
         div {
             class: props.class,
-            style: format!(
-                r#"
-                    grid-column: {} / {};
-                    grid-row: {} / {};
-                    z-index: {};
-                    {}
-                "#,
-                props.x_from,
-                props.x_to,
-                props.y_from,
-                props.y_to,
-                props.z.unwrap_or_default(),
-                props.style.unwrap_or_default()
-            ),
+            style: style.to_string(),
             { props.children }
         }
     )
+}
+
+fn line(n: Option<u8>) -> String {
+    n.map(|n| n.to_string()).unwrap_or(String::from("auto"))
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use super::*;
 
 ::modwire::expose!(
     pub col
+    pub flex
     pub grid_item
     pub grid
     pub page_item
@@ -9,31 +10,47 @@ use super::*;
     pub row
     pub stack_item
     pub stack
+    pub template_areas
+    pub track
+    pub virtual_grid
+    pub virtual_list
+    pub virtual_range
 );
 
-static ABS_POS_RESET: &str = r#"
-    top: 0%;
-    left: 0%;
-"#;
+fn abs_pos_reset() -> style::Style {
+    style::Style::default()
+        .set(style::Property::Top, "0%")
+        .set(style::Property::Left, "0%")
+}
 
-static FILL_VIEW: &str = r#"
-    min-width: 100vw;
-    max-width: 100vw;
-    width: 100vw;
-    min-height: 100vh;
-    max-height: 100vh;
-    height: 100vh;
-"#;
+fn fill_view() -> style::Style {
+    style::Style::default()
+        .set(style::Property::MinWidth, "100vw")
+        .set(style::Property::MaxWidth, "100vw")
+        .set(style::Property::Width, "100vw")
+        .set(style::Property::MinHeight, "100vh")
+        .set(style::Property::MaxHeight, "100vh")
+        .set(style::Property::Height, "100vh")
+}
 
-static FILL: &str = r#"
-    width: 100%;
-    height: 100%;
-    flex: 1;
-"#;
+fn fill() -> style::Style {
+    style::Style::default()
+        .set(style::Property::Width, "100%")
+        .set(style::Property::Height, "100%")
+        .set(style::Property::Flex, 1)
+}
 
 #[derive(Props, Clone, PartialEq)]
 pub struct CommonProps {
+    pub justify: Option<Justify>,
+    pub align: Option<Align>,
+    pub wrap: Option<Wrap>,
+    pub gap: Option<unit::Length>,
+    /// Lays the children out from the end of the main axis.
+    pub reverse: Option<bool>,
+    pub id: Option<String>,
     pub class: Option<String>,
-    pub style: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
     pub children: Option<Element>
 }
\ No newline at end of file
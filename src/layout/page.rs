@@ -15,58 +15,58 @@ pub enum PageScrollSnap {
 #[derive(PartialEq)]
 pub struct PageProps {
     pub scroll_snap: Option<PageScrollSnap>,
+    pub id: Option<String>,
+    /// Id of the scrolling container, for use with `win::use_scroll_progress`.
+    pub scroll_id: Option<String>,
     pub class: Option<String>,
-    pub style: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
     pub overlay: Option<Element>,
     pub children: Option<Element>
 }
 
 #[component]
 pub fn Page(props: PageProps) -> Element {
+    let theme: Option<Signal<theme::Theme>> = try_use_context();
+    let palette: Option<theme::Palette> = theme.map(|theme| theme.read().palette().to_owned());
+    let style: style::Style = style::Style::default()
+        .set(style::Property::JustifyContent, "start")
+        .maybe(style::Property::Background, palette.as_ref().map(|palette| palette.background.to_owned()))
+        .maybe(style::Property::Color, palette.map(|palette| palette.text))
+        .merge(fill_view())
+        .merge(props.style.unwrap_or_default());
+    let overlay_style: style::Style = style::Style::default()
+        .set(style::Property::JustifyContent, "start")
+        .set(style::Property::OverflowX, "hidden")
+        .set(style::Property::OverflowY, "hidden")
+        .set(style::Property::PointerEvents, "none")
+        .merge(fill());
+    let scroll_style: style::Style = style::Style::default()
+        .set(style::Property::JustifyContent, "start")
+        .set(style::Property::OverflowX, "hidden")
+        .set(style::Property::OverflowY, "auto")
+        .set(style::Property::ScrollSnapType, match props.scroll_snap {
+            Some(PageScrollSnap::Mandatory) => "y mandatory",
+            Some(PageScrollSnap::Proximity) => "y proximity",
+            None => "none"
+        })
+        .set(style::Property::ScrollBehavior, "smooth")
+        .merge(fill());
+
     rsx!(
         Stack {
+            id: props.id,
             class: props.class,
-            style: format!(
-                r#"
-                    justify-content: start;
-                    {}
-                    {}
-                "#,
-                FILL_VIEW,
-                props.style.unwrap_or_default()
-            ),
+            style,
             StackItem {
                 z: PAGE_OVERLAY_Z_INDEX,
-                style: format!(
-                    r#"
-                        justify-content: start;
-                        overflow-x: hidden;
-                        overflow-y: hidden;
-                        pointer-events: none;
-                        {}
-                    "#,
-                    FILL
-                ),
+                style: overlay_style,
                 { props.overlay }
             }
             StackItem {
                 z: 0,
-                style: format!(
-                    r#"
-                        justify-content: start;
-                        overflow-x: hidden;
-                        overflow-y: auto;
-                        scroll-snap-type: {};
-                        scroll-behaviour: smooth;
-                        {}
-                    "#,
-                    match props.scroll_snap {
-                        Some(PageScrollSnap::Mandatory) => "y mandatory",
-                        Some(PageScrollSnap::Proximity) => "y proximity",
-                        None => "none"
-                    },
-                    FILL
-                ),
+                id: props.scroll_id,
+                style: scroll_style,
                 { props.children }
             }
         }
@@ -4,52 +4,47 @@ use super::*;
 #[derive(Clone)]
 #[derive(PartialEq)]
 pub struct PageItemProps {
+    /// Id of the item, for use with `win::use_visibility`.
+    pub id: Option<String>,
     pub background: Option<Element>,
+    pub background_gradient: Option<gradient::Gradient>,
     pub top: Option<Element>,
     pub bottom: Option<Element>,
     pub class: Option<String>,
-    pub style: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
     pub children: Option<Element>
 }
 
 #[component]
 pub fn PageItem(props: PageItemProps) -> Element {
+    let style: style::Style = style::Style::default()
+        .set(style::Property::Flex, 1)
+        .merge(fill_view())
+        .merge(props.style.unwrap_or_default());
+    let background_style: style::Style = style::Style::default()
+        .maybe(style::Property::Background, props.background_gradient)
+        .merge(abs_pos_reset())
+        .merge(fill_view());
+    let content_style: style::Style = style::Style::default()
+        .set(style::Property::JustifyContent, "space-between")
+        .set(style::Property::ScrollSnapAlign, "start")
+        .merge(abs_pos_reset())
+        .merge(fill_view());
+
     rsx!(
         Stack {
+            id: props.id,
             class: props.class,
-            style: format!(
-                r#"
-                    flex: 1;
-                    {}
-                    {}
-                "#,
-                FILL_VIEW,
-                props.style.unwrap_or_default()
-            ),
+            style,
             StackItem {
                 z: 0,
-                style: format!(
-                    r#"
-                        {}
-                        {}
-                    "#,
-                    ABS_POS_RESET,
-                    FILL_VIEW
-                ),
+                style: background_style,
                 { props.background }
             }
             StackItem {
                 z: 1,
-                style: format!(
-                    r#"
-                        justify-content: space-between;
-                        scroll-snap-align: start;
-                        {}
-                        {}
-                    "#,
-                    ABS_POS_RESET,
-                    FILL_VIEW
-                ),
+                style: content_style,
                 { props.top }
                 { props.children }
                 { props.bottom }
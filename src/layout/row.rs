@@ -2,16 +2,23 @@ use super::*;
 
 #[component]
 pub fn Row(props: CommonProps) -> Element {
+    let style: style::Style = style::Style::default()
+        .set(style::Property::FlexDirection, if props.reverse.unwrap_or_default() {
+            "row-reverse"
+        } else {
+            "row"
+        })
+        .merge(props.style.unwrap_or_default());
+
     rsx!(
         Col {
+            justify: props.justify,
+            align: props.align,
+            wrap: props.wrap,
+            gap: props.gap,
+            id: props.id,
             class: props.class,
-            style: format!(
-                r#"
-                    flex-direction: row;
-                    {}
-                "#,
-                props.style.unwrap_or_default()
-            ),
+            style,
             { props.children }
         }
     )
@@ -2,16 +2,20 @@ use super::*;
 
 #[component]
 pub fn Stack(props: CommonProps) -> Element {
+    let style: style::Style = style::Style::default()
+        .set(style::Property::Position, "relative")
+        .merge(props.style.unwrap_or_default());
+
     rsx!(
         Col {
+            justify: props.justify,
+            align: props.align,
+            wrap: props.wrap,
+            gap: props.gap,
+            reverse: props.reverse,
+            id: props.id,
             class: props.class,
-            style: format!(
-                r#"
-                    position: relative;
-                    {}
-                "#,
-                props.style.unwrap_or_default()
-            ),
+            style,
             { props.children }
         }
     )
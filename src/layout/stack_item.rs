@@ -5,25 +5,25 @@ use super::*;
 #[derive(PartialEq)]
 pub struct StackItemProps {
     pub z: u64,
+    pub id: Option<String>,
     pub class: Option<String>,
-    pub style: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
     pub children: Option<Element>
 }
 
 #[component]
 pub fn StackItem(props: StackItemProps) -> Element {
+    let style: style::Style = style::Style::default()
+        .set(style::Property::Position, "absolute")
+        .set(style::Property::ZIndex, props.z)
+        .merge(props.style.unwrap_or_default());
+
     rsx!(
         Col {
+            id: props.id,
             class: props.class,
-            style: format!(
-                r#"
-                    position: absolute;
-                    z-index: {};
-                    {}
-                "#,
-                props.z,
-                props.style.unwrap_or_default()
-            ),
+            style,
             { props.children }
         }
     )
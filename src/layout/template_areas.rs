@@ -0,0 +1,187 @@
+use ::std::collections::HashMap;
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum TemplateAreasError {
+    Empty,
+    InvalidName(String),
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize
+    },
+    NonRectangularArea(String)
+}
+
+/// The grid lines an area spans, counted from 1 like CSS.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(PartialEq)]
+pub struct Area {
+    pub x_from: usize,
+    pub x_to: usize,
+    pub y_from: usize,
+    pub y_to: usize
+}
+
+/// A validated `grid-template-areas` definition.
+///
+/// Rows are lines and cells are separated by whitespace. A cell made of dots
+/// is left empty, and every named area must form a filled rectangle.
+///
+/// # Example
+/// ```rs
+/// let areas: TemplateAreas = TemplateAreas::parse(r#"
+///     head head head
+///     nav  main main
+///     nav  foot .
+/// "#)?;
+/// ```
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct TemplateAreas {
+    rows: Vec<Vec<String>>,
+    areas: HashMap<String, Area>
+}
+
+impl TemplateAreas {
+    pub fn parse(source: &str) -> ::std::result::Result<Self, TemplateAreasError> {
+        let rows: Vec<Vec<String>> = source
+            .lines()
+            .map(|line| line.trim().trim_matches('"').trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|cell| cell.to_owned())
+                    .collect()
+            })
+            .collect();
+        let Some(first) = rows.first() else {
+            return Err(TemplateAreasError::Empty)
+        };
+        let expected: usize = first.len();
+        let mut areas: HashMap<String, Area> = HashMap::new();
+        for (y, row) in rows.iter().enumerate() {
+            if row.len() != expected {
+                return Err(TemplateAreasError::RaggedRow {
+                    row: y,
+                    expected,
+                    found: row.len()
+                })
+            }
+            for (x, cell) in row.iter().enumerate() {
+                if is_null_cell(cell) {
+                    continue
+                }
+                if !is_valid_name(cell) {
+                    return Err(TemplateAreasError::InvalidName(cell.to_owned()))
+                }
+                let area: &mut Area = areas.entry(cell.to_owned()).or_insert(Area {
+                    x_from: x + 1,
+                    x_to: x + 2,
+                    y_from: y + 1,
+                    y_to: y + 2
+                });
+                area.x_from = area.x_from.min(x + 1);
+                area.x_to = area.x_to.max(x + 2);
+                area.y_from = area.y_from.min(y + 1);
+                area.y_to = area.y_to.max(y + 2);
+            }
+        }
+        for (name, area) in areas.iter() {
+            let filled: bool = rows[(area.y_from - 1)..(area.y_to - 1)]
+                .iter()
+                .all(|row| {
+                    row[(area.x_from - 1)..(area.x_to - 1)]
+                        .iter()
+                        .all(|cell| cell == name)
+                });
+            if !filled {
+                return Err(TemplateAreasError::NonRectangularArea(name.to_owned()))
+            }
+        }
+        Ok(Self {
+            rows,
+            areas
+        })
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.areas.contains_key(name)
+    }
+
+    pub fn area(&self, name: &str) -> Option<Area> {
+        self.areas.get(name).copied()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.areas.keys().map(|name| name.as_str())
+    }
+}
+
+impl ::std::str::FromStr for TemplateAreas {
+    type Err = TemplateAreasError;
+
+    fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl ::std::fmt::Display for TemplateAreas {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let rows: Vec<String> = self.rows
+            .iter()
+            .map(|row| format!("\"{}\"", row.join(" ")))
+            .collect();
+        write!(f, "{}", rows.join(" "))
+    }
+}
+
+fn is_null_cell(cell: &str) -> bool {
+    cell.chars().all(|c| c == '.')
+}
+
+fn is_valid_name(cell: &str) -> bool {
+    let mut chars: ::std::str::Chars = cell.chars();
+    let Some(first) = chars.next() else {
+        return false
+    };
+    (first.is_alphabetic() || first == '_' || first == '-')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_named_areas() {
+        let areas: TemplateAreas = TemplateAreas::parse(r#"
+            head head head
+            nav  main main
+            nav  foot ..
+        "#).unwrap();
+        assert_eq!(areas.area("nav"), Some(Area {
+            x_from: 1,
+            x_to: 2,
+            y_from: 2,
+            y_to: 4
+        }));
+        assert!(!areas.contains("side"));
+        assert_eq!(areas.to_string(), r#""head head head" "nav main main" "nav foot ..""#);
+    }
+
+    #[test]
+    fn rejects_ragged_and_non_rectangular_areas() {
+        assert_eq!(TemplateAreas::parse("a a\nb"), Err(TemplateAreasError::RaggedRow {
+            row: 1,
+            expected: 2,
+            found: 1
+        }));
+        assert_eq!(TemplateAreas::parse("a a\na b"), Err(TemplateAreasError::NonRectangularArea(String::from("a"))));
+        assert_eq!(TemplateAreas::parse(""), Err(TemplateAreasError::Empty));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use super::*;
+
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Repeat {
+    Count(u16),
+    AutoFill,
+    AutoFit
+}
+
+impl ::std::fmt::Display for Repeat {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Count(n) => write!(f, "{}", n),
+            Self::AutoFill => write!(f, "auto-fill"),
+            Self::AutoFit => write!(f, "auto-fit")
+        }
+    }
+}
+
+/// A grid track size, as used by `grid-template-rows` and `grid-template-columns`.
+///
+/// # Example
+/// ```rs
+/// // repeat(auto-fill, minmax(12rem, 1fr))
+/// Track::auto_fill(vec!(
+///     Track::minmax(Track::Length((Relative::Rem, 12.0).into()), Track::Fr(1.0))
+/// ));
+/// ```
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Track {
+    Fr(f64),
+    Length(unit::Length),
+    Auto,
+    MinContent,
+    MaxContent,
+    MinMax(Box<Track>, Box<Track>),
+    FitContent(unit::Length),
+    Repeat(Repeat, Vec<Track>)
+}
+
+impl Track {
+    pub fn minmax(min: Self, max: Self) -> Self {
+        Self::MinMax(Box::new(min), Box::new(max))
+    }
+
+    pub fn repeat(count: u16, tracks: Vec<Self>) -> Self {
+        Self::Repeat(Repeat::Count(count), tracks)
+    }
+
+    pub fn auto_fill(tracks: Vec<Self>) -> Self {
+        Self::Repeat(Repeat::AutoFill, tracks)
+    }
+
+    pub fn auto_fit(tracks: Vec<Self>) -> Self {
+        Self::Repeat(Repeat::AutoFit, tracks)
+    }
+}
+
+impl From<unit::Length> for Track {
+    fn from(value: unit::Length) -> Self {
+        Self::Length(value)
+    }
+}
+
+impl ::std::fmt::Display for Track {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Fr(n) => write!(f, "{}fr", n),
+            Self::Length(length) => write!(f, "{}", length),
+            Self::Auto => write!(f, "auto"),
+            Self::MinContent => write!(f, "min-content"),
+            Self::MaxContent => write!(f, "max-content"),
+            Self::MinMax(min, max) => write!(f, "minmax({}, {})", min, max),
+            Self::FitContent(length) => write!(f, "fit-content({})", length),
+            Self::Repeat(repeat, tracks) => write!(f, "repeat({}, {})", repeat, track_list(tracks))
+        }
+    }
+}
+
+pub fn track_list(tracks: &[Track]) -> String {
+    tracks
+        .iter()
+        .map(|track| {
+            format!("{}", track)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[repr(u8)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+pub enum AutoFlow {
+    #[default]
+    #[strum(serialize = "row")]
+    Row,
+    #[strum(serialize = "column")]
+    Col,
+    #[strum(serialize = "dense")]
+    Dense,
+    #[strum(serialize = "row dense")]
+    RowDense,
+    #[strum(serialize = "column dense")]
+    ColDense
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_nested_tracks() {
+        let tracks: Vec<Track> = vec!(
+            Track::auto_fill(vec!(
+                Track::minmax(Track::Length(unit::Unit::<unit::Absolute>::from((unit::Absolute::Px, 120.0)).into()), Track::Fr(1.0))
+            )),
+            Track::Auto
+        );
+        assert_eq!(track_list(&tracks), "repeat(auto-fill, minmax(120px, 1fr)) auto");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,119 @@
+use super::*;
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct VirtualGridProps {
+    /// Id of the scrolling element, read through `win`.
+    pub id: &'static str,
+    pub count: usize,
+    /// A fixed number of columns.
+    pub cols: Option<usize>,
+    /// Fits as many columns of at least this many pixels as the width
+    /// allows, when `cols` is not set.
+    pub min_col_w: Option<f64>,
+    pub row_size: ItemSize,
+    /// Rows rendered beyond each edge of the viewport. Defaults to 3.
+    pub overscan: Option<usize>,
+    /// Rows pinned to the top once scrolled past, until the next one. A
+    /// pinned row is rendered only once, in place of its in-flow copy.
+    pub sticky: Option<Vec<usize>>,
+    /// An item index to scroll its row into view.
+    pub scroll_to: Option<usize>,
+    pub render: Callback<usize, Element>,
+    pub class: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>
+}
+
+/// A scrolling grid that only renders the rows in view.
+///
+/// # Example
+/// ```rs
+/// rsx!(
+///     VirtualGrid {
+///         id: "gallery",
+///         count: photos.len(),
+///         min_col_w: 180.0,
+///         row_size: ItemSize::Fixed(200.0),
+///         render: move |i: usize| rsx!(Photo { photo: photos[i].to_owned() })
+///     }
+/// )
+/// ```
+#[component]
+pub fn VirtualGrid(props: VirtualGridProps) -> Element {
+    let element_w: Signal<_> = win::use_element_w(props.id);
+    let cols: usize = match (props.cols, props.min_col_w, element_w()) {
+        (Some(cols), _, _) => cols,
+        (None, Some(min_col_w), Some(Ok(w))) if min_col_w > 0.0 => (w / min_col_w).floor() as usize,
+        _ => 1
+    }.max(1);
+    let sticky: Vec<usize> = props.sticky.unwrap_or_default();
+    let rows: VirtualRows = use_virtual_rows(
+        props.id,
+        props.count.div_ceil(cols),
+        props.row_size,
+        props.overscan.unwrap_or(DEFAULT_OVERSCAN),
+        &sticky,
+        props.scroll_to.map(|i| i / cols)
+    );
+    let style: style::Style = style::Style::default()
+        .set(style::Property::Position, "relative")
+        .set(style::Property::OverflowY, "auto")
+        .merge(props.style.unwrap_or_default());
+    let content_style: style::Style = style::Style::default()
+        .set(style::Property::Position, "relative")
+        .set(style::Property::Width, "100%")
+        .set(style::Property::Height, format!("{}px", rows.total()));
+    let measured: bool = matches!(props.row_size, ItemSize::Measured(_));
+    let count: usize = props.count;
+    let render: Callback<usize, Element> = props.render;
+    let in_flow: Vec<usize> = (rows.range.start..rows.range.end)
+        .filter(|row| Some(*row) != rows.sticky)
+        .collect();
+    let row_items = move |row: usize| {
+        (row * cols)..((row + 1) * cols).min(count)
+    };
+    let columns = move |style: style::Style| {
+        style
+            .set(style::Property::Display, "grid")
+            .set(style::Property::GridTemplateColumns, format!("repeat({}, minmax(0, 1fr))", cols))
+            .to_string()
+    };
+
+    rsx!(
+        div {
+            id: props.id,
+            class: props.class,
+            style: style.to_string(),
+            if let Some(header) = rows.sticky {
+                div {
+                    style: columns(sticky_style(rows.size(header))),
+                    for i in row_items(header) {
+                        { render(i) }
+                    }
+                }
+            }
+            div {
+                style: content_style.to_string(),
+                for row in in_flow {
+                    div {
+                        key: "{row}",
+                        style: columns(row_style(&rows, row, measured)),
+                        onresize: {
+                            let rows: VirtualRows = rows.to_owned();
+                            move |event: Event<ResizeData>| {
+                                if let (true, Ok(size)) = (measured, event.get_border_box_size()) {
+                                    rows.measure(row, size.height);
+                                }
+                            }
+                        },
+                        for i in row_items(row) {
+                            { render(i) }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
\ No newline at end of file
@@ -0,0 +1,227 @@
+use super::*;
+use ::std::cell::RefCell;
+use ::std::collections::HashMap;
+use ::std::rc::Rc;
+
+pub(crate) static DEFAULT_OVERSCAN: usize = 3;
+
+/// The size of virtualized items along the scroll axis, in pixels.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(PartialEq)]
+pub enum ItemSize {
+    Fixed(f64),
+    /// Measured once rendered, assuming the estimate until then.
+    Measured(f64)
+}
+
+impl ItemSize {
+    pub fn estimate(&self) -> f64 {
+        match self {
+            Self::Fixed(size) | Self::Measured(size) => *size
+        }
+    }
+}
+
+/// The state of `use_virtual_rows`.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct VirtualRows {
+    pub range: VirtualRange,
+    pub offsets: Vec<f64>,
+    pub sticky: Option<usize>,
+    sizes: Signal<HashMap<usize, f64>>
+}
+
+impl VirtualRows {
+    pub fn offset(&self, row: usize) -> f64 {
+        self.offsets.get(row).copied().unwrap_or_default()
+    }
+
+    pub fn size(&self, row: usize) -> f64 {
+        self.offset(row + 1) - self.offset(row)
+    }
+
+    pub fn total(&self) -> f64 {
+        self.offsets.last().copied().unwrap_or_default()
+    }
+
+    /// Records the measured size of `row`.
+    pub fn measure(&self, row: usize, size: f64) {
+        let mut sizes: Signal<HashMap<usize, f64>> = self.sizes;
+        if sizes.peek().get(&row) != Some(&size) {
+            sizes.write().insert(row, size);
+        }
+    }
+}
+
+/// Virtualizes `count` rows of the scrolling element with `id`, reading its
+/// scroll position and height through `win`. Scrolls to `scroll_to`
+/// whenever it changes.
+pub fn use_virtual_rows(
+    id: &'static str,
+    count: usize,
+    size: ItemSize,
+    overscan: usize,
+    headers: &[usize],
+    scroll_to: Option<usize>
+) -> VirtualRows {
+    let scroll_top: Signal<_> = win::use_scroll_top(id);
+    let element_h: Signal<_> = win::use_element_h(id);
+    let sizes: Signal<HashMap<usize, f64>> = use_signal(HashMap::new);
+    let latest_offsets: Rc<RefCell<Vec<f64>>> = use_hook(|| Rc::new(RefCell::new(Vec::new())));
+
+    let offsets: Vec<f64> = match size {
+        ItemSize::Fixed(size) => virtual_range::offsets(count, |_| size),
+        ItemSize::Measured(estimate) => {
+            let sizes = sizes.read();
+            virtual_range::offsets(count, |row| sizes.get(&row).copied().unwrap_or(estimate))
+        }
+    };
+    *latest_offsets.borrow_mut() = offsets.to_owned();
+
+    use_effect(use_reactive((&scroll_to,), move |(scroll_to,)| {
+        let Some(row) = scroll_to else {
+            return
+        };
+        let Some(offset) = latest_offsets.borrow().get(row).copied() else {
+            return
+        };
+        let Some(element) = ::web_sys::window()
+            .and_then(|win| win.document())
+            .and_then(|doc| doc.get_element_by_id(id)) else {
+            return
+        };
+        element.set_scroll_top(offset as i32);
+    }));
+
+    let scroll: f64 = match scroll_top() {
+        Some(Ok(scroll)) => scroll,
+        _ => 0.0
+    };
+    let viewport: f64 = match element_h() {
+        Some(Ok(h)) => h,
+        _ => 0.0
+    };
+    VirtualRows {
+        range: visible_range(&offsets, scroll, viewport, overscan),
+        sticky: sticky_header(headers, &offsets, scroll),
+        offsets,
+        sizes
+    }
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct VirtualListProps {
+    /// Id of the scrolling element, read through `win`.
+    pub id: &'static str,
+    pub count: usize,
+    pub size: ItemSize,
+    /// Items rendered beyond each edge of the viewport. Defaults to 3.
+    pub overscan: Option<usize>,
+    /// Items pinned to the top once scrolled past, until the next one. A
+    /// pinned item is rendered only once, in place of its in-flow copy.
+    pub sticky: Option<Vec<usize>>,
+    pub scroll_to: Option<usize>,
+    pub render: Callback<usize, Element>,
+    pub class: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>
+}
+
+/// A scrolling list that only renders the items in view.
+///
+/// # Example
+/// ```rs
+/// rsx!(
+///     VirtualList {
+///         id: "feed",
+///         count: posts.len(),
+///         size: ItemSize::Measured(120.0),
+///         sticky: days.to_owned(),
+///         render: move |i: usize| rsx!(Post { post: posts[i].to_owned() }),
+///         style: Style::default().set(Property::Height, "100%")
+///     }
+/// )
+/// ```
+#[component]
+pub fn VirtualList(props: VirtualListProps) -> Element {
+    let sticky: Vec<usize> = props.sticky.unwrap_or_default();
+    let rows: VirtualRows = use_virtual_rows(
+        props.id,
+        props.count,
+        props.size,
+        props.overscan.unwrap_or(DEFAULT_OVERSCAN),
+        &sticky,
+        props.scroll_to
+    );
+    let style: style::Style = style::Style::default()
+        .set(style::Property::Position, "relative")
+        .set(style::Property::OverflowY, "auto")
+        .merge(props.style.unwrap_or_default());
+    let content_style: style::Style = style::Style::default()
+        .set(style::Property::Position, "relative")
+        .set(style::Property::Width, "100%")
+        .set(style::Property::Height, format!("{}px", rows.total()));
+    let measured: bool = matches!(props.size, ItemSize::Measured(_));
+    let render: Callback<usize, Element> = props.render;
+    let in_flow: Vec<usize> = (rows.range.start..rows.range.end)
+        .filter(|row| Some(*row) != rows.sticky)
+        .collect();
+
+    rsx!(
+        div {
+            id: props.id,
+            class: props.class,
+            style: style.to_string(),
+            if let Some(header) = rows.sticky {
+                div {
+                    style: sticky_style(rows.size(header)).to_string(),
+                    { render(header) }
+                }
+            }
+            div {
+                style: content_style.to_string(),
+                for row in in_flow {
+                    div {
+                        key: "{row}",
+                        style: row_style(&rows, row, measured).to_string(),
+                        onresize: {
+                            let rows: VirtualRows = rows.to_owned();
+                            move |event: Event<ResizeData>| {
+                                if let (true, Ok(size)) = (measured, event.get_border_box_size()) {
+                                    rows.measure(row, size.height);
+                                }
+                            }
+                        },
+                        { render(row) }
+                    }
+                }
+            }
+        }
+    )
+}
+
+pub(crate) fn sticky_style(size: f64) -> style::Style {
+    style::Style::default()
+        .set(style::Property::Position, "sticky")
+        .set(style::Property::Top, 0)
+        .set(style::Property::ZIndex, 1)
+        .set(style::Property::Height, format!("{}px", size))
+        .set(style::Property::MarginBottom, format!("-{}px", size))
+}
+
+pub(crate) fn row_style(rows: &VirtualRows, row: usize, measured: bool) -> style::Style {
+    let style: style::Style = style::Style::default()
+        .set(style::Property::Position, "absolute")
+        .set(style::Property::Top, format!("{}px", rows.offset(row)))
+        .set(style::Property::Left, 0)
+        .set(style::Property::Width, "100%");
+    match measured {
+        true => style,
+        false => style.set(style::Property::Height, format!("{}px", rows.size(row)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+/// A half-open range of item indices.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct VirtualRange {
+    pub start: usize,
+    pub end: usize
+}
+
+impl VirtualRange {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// The start of every item followed by the total length, from the size of
+/// each of `count` items.
+pub fn offsets<T>(count: usize, size: T) -> Vec<f64>
+where
+    T: Fn(usize) -> f64 {
+    let mut offsets: Vec<f64> = Vec::with_capacity(count + 1);
+    let mut at: f64 = 0.0;
+    offsets.push(at);
+    for i in 0..count {
+        at += size(i).max(0.0);
+        offsets.push(at);
+    }
+    offsets
+}
+
+/// The items of `offsets` that intersect the viewport starting at `scroll`
+/// and `viewport` long, widened by `overscan` items on each side.
+pub fn visible_range(offsets: &[f64], scroll: f64, viewport: f64, overscan: usize) -> VirtualRange {
+    let count: usize = offsets.len().saturating_sub(1);
+    if count == 0 {
+        return VirtualRange::default()
+    }
+    let bottom: f64 = scroll + viewport.max(0.0);
+    let start: usize = offsets[1..].partition_point(|end| *end <= scroll).min(count);
+    let end: usize = offsets[..count].partition_point(|start| *start < bottom).max(start);
+    VirtualRange {
+        start: start.saturating_sub(overscan),
+        end: (end + overscan).min(count)
+    }
+}
+
+/// The header to pin at the top: the last of `headers` that starts at or
+/// above `scroll`.
+pub fn sticky_header(headers: &[usize], offsets: &[f64], scroll: f64) -> Option<usize> {
+    headers
+        .iter()
+        .copied()
+        .filter(|header| offsets.get(*header).is_some_and(|offset| *offset <= scroll))
+        .max_by(|a, b| offsets[*a].total_cmp(&offsets[*b]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fixed_sizes() {
+        let offsets: Vec<f64> = offsets(100, |_| 10.0);
+        assert_eq!(offsets[100], 1000.0);
+        assert_eq!(visible_range(&offsets, 0.0, 25.0, 0), VirtualRange { start: 0, end: 3 });
+        assert_eq!(visible_range(&offsets, 95.0, 20.0, 2), VirtualRange { start: 7, end: 14 });
+        assert_eq!(visible_range(&offsets, 990.0, 50.0, 3), VirtualRange { start: 96, end: 100 });
+        assert!(visible_range(&[0.0], 0.0, 100.0, 3).is_empty());
+    }
+
+    #[test]
+    fn variable_sizes() {
+        let offsets: Vec<f64> = offsets(4, |i| [50.0, 10.0, 10.0, 100.0][i]);
+        assert_eq!(offsets, vec!(0.0, 50.0, 60.0, 70.0, 170.0));
+        assert_eq!(visible_range(&offsets, 55.0, 10.0, 0), VirtualRange { start: 1, end: 3 });
+        assert_eq!(visible_range(&offsets, 60.0, 0.0, 0), VirtualRange { start: 2, end: 2 });
+    }
+
+    #[test]
+    fn pins_the_last_header_above_the_scroll() {
+        let offsets: Vec<f64> = offsets(10, |_| 10.0);
+        assert_eq!(sticky_header(&[0, 5], &offsets, 0.0), Some(0));
+        assert_eq!(sticky_header(&[0, 5], &offsets, 49.0), Some(0));
+        assert_eq!(sticky_header(&[0, 5], &offsets, 50.0), Some(5));
+        assert_eq!(sticky_header(&[3], &offsets, 10.0), None);
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 use ::dioxus::prelude::*;
 
 pub mod layout;
+pub mod motion;
 pub mod win;
 
 #[cfg(feature = "binding")]
@@ -9,6 +10,14 @@ pub mod binding;
 pub mod color;
 pub mod direction;
 pub mod easing;
+pub mod font;
+pub mod gradient;
+pub mod markdown;
+pub mod measure;
+pub mod responsive;
+pub mod style;
+pub mod stylesheet;
+pub mod theme;
 pub mod typography;
 pub mod unit;
 
@@ -0,0 +1,580 @@
+use super::*;
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Block {
+    Heading {
+        level: u8,
+        content: Vec<Inline>
+    },
+    Paragraph(Vec<Inline>),
+    List {
+        ordered: bool,
+        items: Vec<Vec<Block>>
+    },
+    CodeBlock {
+        language: Option<String>,
+        code: String
+    },
+    Blockquote(Vec<Block>),
+    Rule
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Inline {
+    Text(String),
+    Emphasis(Vec<Inline>),
+    Strong(Vec<Inline>),
+    Code(String),
+    Link {
+        href: String,
+        content: Vec<Inline>
+    }
+}
+
+/// Parses the subset of CommonMark covering ATX headings, paragraphs,
+/// emphasis, links, inline and fenced code, lists, blockquotes and
+/// thematic breaks. Anything else is kept as text.
+pub fn parse(source: &str) -> Vec<Block> {
+    let lines: Vec<&str> = source.lines().collect();
+    parse_lines(&lines)
+}
+
+fn parse_lines(lines: &[&str]) -> Vec<Block> {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut i: usize = 0;
+    while i < lines.len() {
+        let line: &str = lines[i];
+        let trimmed: &str = line.trim_start();
+        if trimmed.is_empty() {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            i += 1;
+            continue
+        }
+        if let Some(fence) = fence(trimmed) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            let language: &str = trimmed[fence.len()..].trim();
+            let mut code: Vec<&str> = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with(fence) {
+                code.push(lines[i]);
+                i += 1;
+            }
+            blocks.push(Block::CodeBlock {
+                language: (!language.is_empty()).then(|| language.to_owned()),
+                code: code.join("\n")
+            });
+            i += 1;
+            continue
+        }
+        if let Some((level, content)) = heading(trimmed) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading {
+                level,
+                content: parse_inline(content)
+            });
+            i += 1;
+            continue
+        }
+        if is_rule(trimmed) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(Block::Rule);
+            i += 1;
+            continue
+        }
+        if trimmed.starts_with('>') {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            let mut quoted: Vec<&str> = Vec::new();
+            while i < lines.len() && lines[i].trim_start().starts_with('>') {
+                let quoted_line: &str = &lines[i].trim_start()[1..];
+                quoted.push(quoted_line.strip_prefix(' ').unwrap_or(quoted_line));
+                i += 1;
+            }
+            blocks.push(Block::Blockquote(parse_lines(&quoted)));
+            continue
+        }
+        if let Some((ordered, _)) = list_marker(trimmed) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            let mut items: Vec<Vec<Block>> = Vec::new();
+            while i < lines.len() {
+                let trimmed: &str = lines[i].trim_start();
+                let Some((item_ordered, width)) = list_marker(trimmed) else {
+                    break
+                };
+                if item_ordered != ordered {
+                    break
+                }
+                let mut item: Vec<&str> = vec!(&trimmed[width..]);
+                i += 1;
+                while i < lines.len() {
+                    let continuation: &str = lines[i];
+                    let indent: usize = continuation.chars().take_while(|c| c.is_whitespace()).count();
+                    if continuation.trim().is_empty() || indent < 2 {
+                        break
+                    }
+                    let start: usize = continuation
+                        .char_indices()
+                        .nth(indent.min(width))
+                        .map(|(at, _)| at)
+                        .unwrap_or(continuation.len());
+                    item.push(&continuation[start..]);
+                    i += 1;
+                }
+                items.push(parse_lines(&item));
+                let next_is_item: bool = lines
+                    .get(i + 1)
+                    .and_then(|next| list_marker(next.trim_start()))
+                    .is_some_and(|(next_ordered, _)| next_ordered == ordered);
+                if i < lines.len() && lines[i].trim().is_empty() && next_is_item {
+                    i += 1;
+                }
+            }
+            blocks.push(Block::List {
+                ordered,
+                items
+            });
+            continue
+        }
+        paragraph.push(trimmed);
+        i += 1;
+    }
+    flush_paragraph(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn flush_paragraph(paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>) {
+    if paragraph.is_empty() {
+        return
+    }
+    let text: String = paragraph.join(" ");
+    blocks.push(Block::Paragraph(parse_inline(&text)));
+    paragraph.clear();
+}
+
+fn fence(line: &str) -> Option<&'static str> {
+    if line.starts_with("```") {
+        return Some("```")
+    }
+    if line.starts_with("~~~") {
+        return Some("~~~")
+    }
+    None
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level: usize = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None
+    }
+    let rest: &str = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None
+    }
+    let content: &str = rest.trim();
+    let unclosed: &str = content.trim_end_matches('#');
+    if unclosed.is_empty() {
+        return Some((level as u8, unclosed))
+    }
+    if unclosed.ends_with(char::is_whitespace) {
+        return Some((level as u8, unclosed.trim_end()))
+    }
+    Some((level as u8, content))
+}
+
+fn is_rule(line: &str) -> bool {
+    let marks: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    marks.len() >= 3 && ['-', '*', '_'].iter().any(|mark| marks.chars().all(|c| c == *mark))
+}
+
+/// Whether `line` starts a list item, and the width of its marker.
+fn list_marker(line: &str) -> Option<(bool, usize)> {
+    if line.starts_with("- ") || line.starts_with("* ") || line.starts_with("+ ") {
+        return Some((false, 2))
+    }
+    let digits: usize = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 || digits > 9 {
+        return None
+    }
+    let rest: &str = &line[digits..];
+    if rest.starts_with(". ") || rest.starts_with(") ") {
+        return Some((true, digits + 2))
+    }
+    None
+}
+
+pub fn parse_inline(source: &str) -> Vec<Inline> {
+    let mut inlines: Vec<Inline> = Vec::new();
+    let mut text: String = String::new();
+    let mut rest: &str = source;
+    while let Some(c) = rest.chars().next() {
+        let parsed: Option<(Inline, usize)> = match c {
+            '`' => code_span(rest),
+            '*' => emphasis(rest, c),
+            '_' if !text.ends_with(char::is_alphanumeric) => emphasis(rest, c),
+            '[' => link(rest),
+            '\\' => {
+                let escaped: Option<char> = rest[1..].chars().next().filter(|c| c.is_ascii_punctuation());
+                if let Some(escaped) = escaped {
+                    text.push(escaped);
+                    rest = &rest[1 + escaped.len_utf8()..];
+                    continue
+                }
+                None
+            },
+            _ => None
+        };
+        match parsed {
+            Some((inline, consumed)) => {
+                if !text.is_empty() {
+                    inlines.push(Inline::Text(::std::mem::take(&mut text)));
+                }
+                inlines.push(inline);
+                rest = &rest[consumed..];
+            },
+            None => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    if !text.is_empty() {
+        inlines.push(Inline::Text(text));
+    }
+    inlines
+}
+
+fn code_span(source: &str) -> Option<(Inline, usize)> {
+    let ticks: usize = source.chars().take_while(|c| *c == '`').count();
+    let fence: &str = &source[..ticks];
+    let end: usize = source[ticks..].find(fence)?;
+    let code: &str = &source[ticks..ticks + end];
+    Some((Inline::Code(code.trim().to_owned()), ticks + end + ticks))
+}
+
+fn emphasis(source: &str, mark: char) -> Option<(Inline, usize)> {
+    let strong: String = format!("{}{}", mark, mark);
+    if let Some(inner) = source.strip_prefix(strong.as_str()) {
+        let end: usize = inner.find(strong.as_str()).filter(|end| *end > 0)?;
+        return Some((Inline::Strong(parse_inline(&inner[..end])), end + 4))
+    }
+    let inner: &str = &source[1..];
+    if inner.starts_with(char::is_whitespace) {
+        return None
+    }
+    let end: usize = inner.find(mark).filter(|end| *end > 0)?;
+    Some((Inline::Emphasis(parse_inline(&inner[..end])), end + 2))
+}
+
+fn link(source: &str) -> Option<(Inline, usize)> {
+    let mut depth: usize = 0;
+    let mut close: Option<usize> = None;
+    for (i, c) in source.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(i);
+                    break
+                }
+            },
+            _ => {}
+        }
+    }
+    let close: usize = close?;
+    let target: &str = source[close + 1..].strip_prefix('(')?;
+    let end: usize = target.find(')')?;
+    Some((
+        Inline::Link {
+            href: target[..end].trim().to_owned(),
+            content: parse_inline(&source[1..close])
+        },
+        close + 2 + end + 1
+    ))
+}
+
+/// Replaces how individual node types are rendered by `Markdown`.
+///
+/// # Example
+/// ```rs
+/// let renderers = Renderers {
+///     link: Some(Callback::new(|inline: Inline| {
+///         let Inline::Link { href, .. } = inline else {
+///             return rsx!()
+///         };
+///         rsx!(Link { to: href, "↗" })
+///     })),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct Renderers {
+    pub heading: Option<Callback<Block, Element>>,
+    pub paragraph: Option<Callback<Block, Element>>,
+    pub list: Option<Callback<Block, Element>>,
+    pub code_block: Option<Callback<Block, Element>>,
+    pub blockquote: Option<Callback<Block, Element>>,
+    pub rule: Option<Callback<Block, Element>>,
+    pub text: Option<Callback<Inline, Element>>,
+    pub emphasis: Option<Callback<Inline, Element>>,
+    pub strong: Option<Callback<Inline, Element>>,
+    pub code: Option<Callback<Inline, Element>>,
+    pub link: Option<Callback<Inline, Element>>
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct MarkdownProps {
+    pub source: String,
+    pub renderers: Option<Renderers>,
+    pub class: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>
+}
+
+/// Renders Markdown with `Typography` variants inside a `layout::Col`.
+#[component]
+pub fn Markdown(props: MarkdownProps) -> Element {
+    let blocks: Vec<Block> = parse(&props.source);
+    let renderers: Renderers = props.renderers.unwrap_or_default();
+
+    rsx!(
+        layout::Col {
+            class: props.class,
+            style: style::Style::default()
+                .set(style::Property::AlignItems, "stretch")
+                .merge(props.style.unwrap_or_default()),
+            { render_blocks(&blocks, &renderers) }
+        }
+    )
+}
+
+fn render_blocks(blocks: &[Block], renderers: &Renderers) -> Element {
+    rsx!(
+        for block in blocks {
+            { render_block(block, renderers) }
+        }
+    )
+}
+
+fn render_block(block: &Block, renderers: &Renderers) -> Element {
+    let renderer: Option<Callback<Block, Element>> = match block {
+        Block::Heading { .. } => renderers.heading,
+        Block::Paragraph(_) => renderers.paragraph,
+        Block::List { .. } => renderers.list,
+        Block::CodeBlock { .. } => renderers.code_block,
+        Block::Blockquote(_) => renderers.blockquote,
+        Block::Rule => renderers.rule
+    };
+    if let Some(renderer) = renderer {
+        return renderer.call(block.to_owned())
+    }
+    match block {
+        Block::Heading { level, content } => {
+            let (variant, tag) = match level {
+                1 => (typography::Variant::Display, typography::Tag::H1),
+                2 => (typography::Variant::Headline, typography::Tag::H2),
+                3 => (typography::Variant::Title, typography::Tag::H3),
+                4 => (typography::Variant::Title, typography::Tag::H4),
+                5 => (typography::Variant::Title, typography::Tag::H5),
+                _ => (typography::Variant::Title, typography::Tag::H6)
+            };
+            rsx!(
+                typography::Typography::<String> {
+                    variant,
+                    tag,
+                    { render_inlines(content, renderers) }
+                }
+            )
+        },
+        Block::Paragraph(content) => rsx!(
+            typography::Typography::<String> {
+                variant: typography::Variant::Body,
+                { render_inlines(content, renderers) }
+            }
+        ),
+        Block::List { ordered, items } => {
+            let items: Element = rsx!(
+                for item in items {
+                    li {
+                        { render_blocks(item, renderers) }
+                    }
+                }
+            );
+            if *ordered {
+                rsx!(ol { { items } })
+            } else {
+                rsx!(ul { { items } })
+            }
+        },
+        Block::CodeBlock { language, code } => rsx!(
+            pre {
+                code {
+                    class: language.as_ref().map(|language| format!("language-{}", language)),
+                    { code.to_owned() }
+                }
+            }
+        ),
+        Block::Blockquote(blocks) => rsx!(
+            blockquote {
+                layout::Col {
+                    style: style::Style::default().set(style::Property::AlignItems, "stretch"),
+                    { render_blocks(blocks, renderers) }
+                }
+            }
+        ),
+        Block::Rule => rsx!(hr {})
+    }
+}
+
+fn render_inlines(inlines: &[Inline], renderers: &Renderers) -> Element {
+    rsx!(
+        for inline in inlines {
+            { render_inline(inline, renderers) }
+        }
+    )
+}
+
+fn render_inline(inline: &Inline, renderers: &Renderers) -> Element {
+    let renderer: Option<Callback<Inline, Element>> = match inline {
+        Inline::Text(_) => renderers.text,
+        Inline::Emphasis(_) => renderers.emphasis,
+        Inline::Strong(_) => renderers.strong,
+        Inline::Code(_) => renderers.code,
+        Inline::Link { .. } => renderers.link
+    };
+    if let Some(renderer) = renderer {
+        return renderer.call(inline.to_owned())
+    }
+    match inline {
+        Inline::Text(text) => rsx!({ text.to_owned() }),
+        Inline::Emphasis(content) => rsx!(
+            em {
+                { render_inlines(content, renderers) }
+            }
+        ),
+        Inline::Strong(content) => rsx!(
+            typography::TextSpan {
+                weight: typography::Weight::Bold,
+                { render_inlines(content, renderers) }
+            }
+        ),
+        Inline::Code(code) => rsx!(
+            typography::TextSpan {
+                code: true,
+                { code.to_owned() }
+            }
+        ),
+        Inline::Link { href, content } if !is_safe_href(href) => render_inlines(content, renderers),
+        Inline::Link { href, content } => rsx!(
+            typography::TextSpan {
+                href: Url::External(href.to_owned()),
+                { render_inlines(content, renderers) }
+            }
+        )
+    }
+}
+
+/// Whether `href` is relative or uses `http`, `https` or `mailto`, so that
+/// links in untrusted markdown cannot run script.
+fn is_safe_href(href: &str) -> bool {
+    let href: String = href
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let Some(colon) = href.find(':') else {
+        return true
+    };
+    if href[..colon].contains(['/', '?', '#']) {
+        return true
+    }
+    ["http", "https", "mailto"].contains(&&href[..colon])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn text(s: &str) -> Inline {
+        Inline::Text(s.to_owned())
+    }
+
+    #[test]
+    fn parses_blocks() {
+        let blocks: Vec<Block> = parse("# Title\n\nSome *soft*\ntext.\n\n- one\n- two\n  continued\n\n> quoted\n\n```rs\nlet x = 1;\n```\n---");
+        assert_eq!(blocks, vec!(
+            Block::Heading {
+                level: 1,
+                content: vec!(text("Title"))
+            },
+            Block::Paragraph(vec!(text("Some "), Inline::Emphasis(vec!(text("soft"))), text(" text."))),
+            Block::List {
+                ordered: false,
+                items: vec!(
+                    vec!(Block::Paragraph(vec!(text("one")))),
+                    vec!(Block::Paragraph(vec!(text("two continued"))))
+                )
+            },
+            Block::Blockquote(vec!(Block::Paragraph(vec!(text("quoted"))))),
+            Block::CodeBlock {
+                language: Some("rs".to_owned()),
+                code: "let x = 1;".to_owned()
+            },
+            Block::Rule
+        ));
+    }
+
+    #[test]
+    fn parses_inlines() {
+        assert_eq!(parse_inline("**bold [link](https://a.b)** and `a*b` \\*"), vec!(
+            Inline::Strong(vec!(
+                text("bold "),
+                Inline::Link {
+                    href: "https://a.b".to_owned(),
+                    content: vec!(text("link"))
+                }
+            )),
+            text(" and "),
+            Inline::Code("a*b".to_owned()),
+            text(" *")
+        ));
+    }
+
+    #[test]
+    fn strips_unicode_continuation_indent() {
+        let blocks: Vec<Block> = parse("- one\n\u{3000}\u{3000}two\n- three\n\u{a0}\u{a0}four");
+        assert_eq!(blocks, vec!(Block::List {
+            ordered: false,
+            items: vec!(
+                vec!(Block::Paragraph(vec!(text("one two")))),
+                vec!(Block::Paragraph(vec!(text("three four"))))
+            )
+        }));
+    }
+
+    #[test]
+    fn keeps_hashes_that_are_not_a_closing_sequence() {
+        assert_eq!(heading("# C#"), Some((1, "C#")));
+        assert_eq!(heading("## Title ##"), Some((2, "Title")));
+        assert_eq!(heading("### ###"), Some((3, "")));
+    }
+
+    #[test]
+    fn rejects_scripted_hrefs() {
+        assert!(is_safe_href("https://a.b"));
+        assert!(is_safe_href("mailto:a@b.c"));
+        assert!(is_safe_href("/docs/page?x=a:b"));
+        assert!(is_safe_href("#section"));
+        assert!(!is_safe_href("javascript:alert(1)"));
+        assert!(!is_safe_href(" Java\tScript:alert(1)"));
+        assert!(!is_safe_href("data:text/html,<script></script>"));
+    }
+}
\ No newline at end of file
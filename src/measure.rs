@@ -0,0 +1,108 @@
+use super::*;
+use ::wasm_bindgen::JsCast;
+use ::std::cell::RefCell;
+use ::std::collections::HashMap;
+
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    MissingWindow,
+    MissingDocument,
+    MissingCanvasContext,
+    CanvasConstructionFailure(::wasm_bindgen::JsValue),
+    MeasurementFailure(::wasm_bindgen::JsValue)
+}
+
+static CACHE_CAPACITY: usize = 1024;
+
+thread_local! {
+    static CONTEXT: RefCell<Option<::web_sys::CanvasRenderingContext2d>> = const { RefCell::new(None) };
+    static CACHE: RefCell<HashMap<(String, String), TextMetrics>> = RefCell::new(HashMap::new());
+}
+
+/// Pixel metrics of a run of text.
+///
+/// `ascent` and `descent` are those of the font's bounding box, shared by
+/// every run in the same font; the `actual_` variants bound the glyphs
+/// that were measured.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct TextMetrics {
+    pub width: f64,
+    pub ascent: f64,
+    pub descent: f64,
+    pub actual_ascent: f64,
+    pub actual_descent: f64
+}
+
+impl TextMetrics {
+    pub fn height(&self) -> f64 {
+        self.ascent + self.descent
+    }
+
+    pub fn width_unit(&self) -> unit::Unit<unit::Absolute> {
+        unit::Absolute::Px.into_unit(self.width)
+    }
+
+    pub fn height_unit(&self) -> unit::Unit<unit::Absolute> {
+        unit::Absolute::Px.into_unit(self.height())
+    }
+}
+
+/// Measures `text` rendered in `font`, a CSS `font` shorthand such as
+/// `"600 16px Inter"`, on an offscreen canvas.
+///
+/// Results are cached per font and text. Fonts that have not loaded yet
+/// are measured with their fallback; see `font::use_font_loaded`.
+pub fn measure_text(font: &str, text: &str) -> Result<TextMetrics> {
+    let key: (String, String) = (font.to_owned(), text.to_owned());
+    if let Some(metrics) = CACHE.with_borrow(|cache| cache.get(&key).cloned()) {
+        return Ok(metrics)
+    }
+    let metrics: TextMetrics = CONTEXT.with_borrow_mut(|context| {
+        if context.is_none() {
+            *context = Some(canvas_context()?);
+        }
+        let Some(context) = context.as_ref() else {
+            return Err(Error::MissingCanvasContext)
+        };
+        context.set_font(font);
+        let metrics: ::web_sys::TextMetrics = context.measure_text(text).map_err(Error::MeasurementFailure)?;
+        Ok(TextMetrics {
+            width: metrics.width(),
+            ascent: metrics.font_bounding_box_ascent(),
+            descent: metrics.font_bounding_box_descent(),
+            actual_ascent: metrics.actual_bounding_box_ascent(),
+            actual_descent: metrics.actual_bounding_box_descent()
+        })
+    })?;
+    CACHE.with_borrow_mut(|cache| {
+        if cache.len() >= CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(key, metrics.to_owned());
+    });
+    Ok(metrics)
+}
+
+/// Drops every cached measurement, such as after a web font finishes loading.
+pub fn clear_measure_cache() {
+    CACHE.with_borrow_mut(|cache| cache.clear());
+}
+
+fn canvas_context() -> Result<::web_sys::CanvasRenderingContext2d> {
+    let win: ::web_sys::Window = ::web_sys::window().ok_or(Error::MissingWindow)?;
+    let doc: ::web_sys::Document = win.document().ok_or(Error::MissingDocument)?;
+    let canvas: ::web_sys::HtmlCanvasElement = doc.create_element("canvas")
+        .map_err(Error::CanvasConstructionFailure)?
+        .dyn_into()
+        .map_err(|_| Error::MissingCanvasContext)?;
+    canvas.get_context("2d")
+        .map_err(Error::CanvasConstructionFailure)?
+        .ok_or(Error::MissingCanvasContext)?
+        .dyn_into()
+        .map_err(|_| Error::MissingCanvasContext)
+}
\ No newline at end of file
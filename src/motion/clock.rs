@@ -0,0 +1,42 @@
+use ::std::time;
+
+/// A monotonic time source used to drive a `Player`.
+pub trait Clock {
+    fn now(&self) -> time::Duration;
+}
+
+/// Reads the browser's wall clock through `Date.now()`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct WinClock;
+
+impl Clock for WinClock {
+    fn now(&self) -> time::Duration {
+        let ms: f64 = ::js_sys::Date::now();
+        time::Duration::from_secs_f64(ms / 1000.0)
+    }
+}
+
+/// A manually advanced clock for running timelines off-browser.
+/// Clones share the same underlying time.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct FakeClock(::std::rc::Rc<::std::cell::Cell<time::Duration>>);
+
+impl FakeClock {
+    pub fn advance(&self, duration: time::Duration) {
+        self.0.set(self.0.get() + duration);
+    }
+
+    pub fn set(&self, now: time::Duration) {
+        self.0.set(now);
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> time::Duration {
+        self.0.get()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use super::*;
+
+#[cfg(feature = "binding")]
+#[cfg(feature = "win-binding")]
+::modwire::expose!(
+    pub playback
+);
+
+::modwire::expose!(
+    pub clock
+    pub player
+    pub scroll_linked
+    pub timeline
+    pub tween
+);
\ No newline at end of file
@@ -0,0 +1,44 @@
+use super::*;
+
+/// Plays `timeline` on the browser's animation frame loop. The loop is
+/// cancelled once the timeline finishes or the component is dropped.
+/// When the user prefers reduced motion each tween renders its end state,
+/// unless it or its timeline was built with `ReducedMotion::Ignore`.
+pub fn use_timeline(timeline: Timeline) -> Signal<Option<win::Result<Frame>>> {
+    let prefers_reduced_motion: Signal<_> = win::use_prefers_reduced_motion();
+    let ret: Signal<Option<win::Result<Frame>>> = use_signal(|| None);
+    let drop_token: Signal<Option<win::DropToken>> = use_signal(|| None);
+
+    use_effect({
+        let mut ret: Signal<_> = ret.to_owned();
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            let mut player: Player<_> = Player::new(timeline.to_owned(), WinClock);
+            match win::on_animation_frame(move || {
+                let reduced_motion: bool = matches!(*prefers_reduced_motion.peek(), Some(Ok(true)));
+                player.set_reduced_motion(reduced_motion);
+                ret.set(Some(Ok(player.sample())));
+                if player.is_finished() {
+                    drop_token.set(None);
+                }
+            }) {
+                Ok(new_drop_token) => {
+                    drop_token.set(None);
+                    drop_token.set(Some(new_drop_token));
+                },
+                Err(e) => {
+                    ret.set(Some(Err(win::Error::EventListenerAttachmentFailure(e))));
+                }
+            }
+        }
+    });
+
+    use_drop({
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            drop_token.set(None);
+        }
+    });
+
+    ret
+}
\ No newline at end of file
@@ -0,0 +1,109 @@
+use super::*;
+use ::std::time;
+
+/// Plays a `Timeline` against a `Clock`.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Player<T>
+where
+    T: Clock {
+    timeline: Timeline,
+    clock: T,
+    started: time::Duration,
+    reduced_motion: bool
+}
+
+impl<T> Player<T>
+where
+    T: Clock {
+    pub fn new(timeline: Timeline, clock: T) -> Self {
+        let started: time::Duration = clock.now();
+        Self {
+            timeline,
+            clock,
+            started,
+            reduced_motion: false
+        }
+    }
+
+    /// Tells the player whether the user prefers reduced motion. Tweens that
+    /// respect it, through their own setting or the timeline's, then jump to
+    /// their end state; the rest keep playing.
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.reduced_motion = reduced_motion;
+    }
+
+    pub fn restart(&mut self) {
+        self.started = self.clock.now();
+    }
+
+    pub fn elapsed(&self) -> time::Duration {
+        if self.reduced_motion && self.timeline.respects_reduced_motion() {
+            return self.timeline.duration()
+        }
+        self.clock.now().saturating_sub(self.started)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed() >= self.timeline.duration()
+    }
+
+    pub fn sample(&self) -> Frame {
+        self.timeline.sample_with_reduced_motion(self.elapsed(), self.reduced_motion)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fake_clock_drives_playback() {
+        let clock: FakeClock = FakeClock::default();
+        clock.set(time::Duration::from_secs(10));
+        let timeline: Timeline = Timeline::default()
+            .then("a", Tween::new(0.0, 10.0, time::Duration::from_millis(100), easing::linear));
+        let player: Player<_> = Player::new(timeline, clock.to_owned());
+        assert_eq!(player.sample()["a"], 0.0);
+        clock.advance(time::Duration::from_millis(100));
+        assert!(player.is_finished());
+        assert_eq!(player.sample()["a"], 10.0);
+    }
+
+    #[test]
+    fn reduced_motion_jumps_to_end_unless_ignored() {
+        let tween: Tween = Tween::new(0.0, 10.0, time::Duration::from_millis(100), easing::linear);
+        let timeline: Timeline = Timeline::default().then("a", tween);
+        let mut player: Player<_> = Player::new(timeline.to_owned(), FakeClock::default());
+        player.set_reduced_motion(true);
+        assert!(player.is_finished());
+        assert_eq!(player.sample()["a"], 10.0);
+        let timeline: Timeline = timeline.reduced_motion(ReducedMotion::Ignore);
+        let mut player: Player<_> = Player::new(timeline, FakeClock::default());
+        player.set_reduced_motion(true);
+        assert_eq!(player.sample()["a"], 0.0);
+    }
+
+    #[test]
+    fn tweens_override_the_reduced_motion_of_their_timeline() {
+        let tween: Tween = Tween::new(0.0, 10.0, time::Duration::from_millis(100), easing::linear);
+        let clock: FakeClock = FakeClock::default();
+        let timeline: Timeline = Timeline::default()
+            .then("a", tween.to_owned())
+            .with("b", tween.to_owned().reduced_motion(ReducedMotion::Ignore));
+        let mut player: Player<_> = Player::new(timeline, clock.to_owned());
+        player.set_reduced_motion(true);
+        clock.advance(time::Duration::from_millis(50));
+        assert_eq!(player.sample()["a"], 10.0);
+        assert_eq!(player.sample()["b"], 5.0);
+        let timeline: Timeline = Timeline::default()
+            .then("a", tween.to_owned())
+            .with("b", tween.reduced_motion(ReducedMotion::Respect))
+            .reduced_motion(ReducedMotion::Ignore);
+        let mut player: Player<_> = Player::new(timeline, clock.to_owned());
+        player.set_reduced_motion(true);
+        clock.advance(time::Duration::from_millis(50));
+        assert_eq!(player.sample()["a"], 5.0);
+        assert_eq!(player.sample()["b"], 10.0);
+    }
+}
\ No newline at end of file
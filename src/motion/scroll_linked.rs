@@ -0,0 +1,19 @@
+use super::*;
+
+/// Drives `timeline` from a progress signal instead of time, such as
+/// `win::use_scroll_progress` or `win::use_visibility`.
+///
+/// # Example
+/// ```rs
+/// let progress = win::use_scroll_progress("page");
+/// let frame = motion::use_scroll_timeline(progress, Timeline::default()
+///     .then("parallax", Tween::new(0.0, -120.0, ms(1000), easing::linear)));
+/// ```
+pub fn use_scroll_timeline(progress: Signal<Option<win::Result<f64>>>, timeline: Timeline) -> Memo<Option<win::Result<Frame>>> {
+    use_memo(move || {
+        let progress: Option<win::Result<f64>> = progress();
+        progress.map(|progress| {
+            progress.map(|progress| timeline.sample_progress(progress))
+        })
+    })
+}
\ No newline at end of file
@@ -0,0 +1,298 @@
+use super::*;
+use ::std::time;
+use ::std::collections::HashMap;
+
+pub type Frame = HashMap<&'static str, f32>;
+
+/// Where an entry is placed on a `Timeline`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Position {
+    /// After everything already on the timeline.
+    End,
+    /// Alongside the previously added entry.
+    WithPrevious,
+    /// At an absolute time from the start of the timeline.
+    At(time::Duration),
+    /// At a label added with `Timeline::label`.
+    Label(&'static str),
+    /// A gap after the end of the timeline.
+    After(time::Duration),
+    /// Overlapping the end of the timeline.
+    Before(time::Duration)
+}
+
+/// How a `Timeline`, or a `Tween` within one, reacts to the user's
+/// `prefers-reduced-motion` setting.
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub enum ReducedMotion {
+    /// Jump straight to the end state when reduced motion is requested.
+    #[default]
+    Respect,
+    /// Play regardless, for motion that conveys essential information.
+    Ignore
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+struct Entry {
+    key: &'static str,
+    start: time::Duration,
+    tween: Tween
+}
+
+impl Entry {
+    fn end(&self) -> time::Duration {
+        self.start + self.tween.duration
+    }
+}
+
+/// Choreographs tweens keyed by name.
+///
+/// # Example
+/// ```rs
+/// let timeline = Timeline::default()
+///     .then("title", Tween::new(0.0, 1.0, ms(300), easing::ease_out))
+///     .with("subtitle", Tween::new(0.0, 1.0, ms(300), easing::ease_out))
+///     .label("cards")
+///     .stagger(Position::Label("cards"), &["a", "b", "c"], tween, ms(80));
+/// ```
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct Timeline {
+    entries: Vec<Entry>,
+    labels: HashMap<&'static str, time::Duration>,
+    previous_start: time::Duration,
+    reduced_motion: ReducedMotion
+}
+
+impl Timeline {
+    pub fn add(mut self, position: Position, key: &'static str, tween: Tween) -> Self {
+        let start: time::Duration = self.resolve(&position);
+        self.entries.push(Entry {
+            key,
+            start,
+            tween
+        });
+        self.previous_start = start;
+        self
+    }
+
+    pub fn reduced_motion(mut self, reduced_motion: ReducedMotion) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    /// Whether every tween holds its end under reduced motion. Tweens
+    /// without their own setting follow the timeline's.
+    pub fn respects_reduced_motion(&self) -> bool {
+        self.entries.iter().all(|entry| self.respects(entry))
+    }
+
+    fn respects(&self, entry: &Entry) -> bool {
+        entry.tween.reduced_motion.as_ref().unwrap_or(&self.reduced_motion) == &ReducedMotion::Respect
+    }
+
+    pub fn then(self, key: &'static str, tween: Tween) -> Self {
+        self.add(Position::End, key, tween)
+    }
+
+    pub fn with(self, key: &'static str, tween: Tween) -> Self {
+        self.add(Position::WithPrevious, key, tween)
+    }
+
+    pub fn stagger(mut self, position: Position, keys: &[&'static str], tween: Tween, each: time::Duration) -> Self {
+        let start: time::Duration = self.resolve(&position);
+        for (i, key) in keys.iter().enumerate() {
+            let offset: time::Duration = each * i as u32;
+            self = self.add(Position::At(start + offset), key, tween.to_owned());
+        }
+        self.previous_start = start;
+        self
+    }
+
+    /// Marks the current end of the timeline with `name`.
+    pub fn label(mut self, name: &'static str) -> Self {
+        let at: time::Duration = self.duration();
+        self.labels.insert(name, at);
+        self
+    }
+
+    /// Places every entry and label of `other` at `position`.
+    pub fn append(mut self, position: Position, other: Self) -> Self {
+        let start: time::Duration = self.resolve(&position);
+        for mut entry in other.entries {
+            entry.tween.reduced_motion.get_or_insert(other.reduced_motion.to_owned());
+            self.entries.push(Entry {
+                start: start + entry.start,
+                ..entry
+            });
+        }
+        for (name, at) in other.labels {
+            self.labels.insert(name, start + at);
+        }
+        self.previous_start = start;
+        self
+    }
+
+    pub fn sequence(self, other: Self) -> Self {
+        self.append(Position::End, other)
+    }
+
+    pub fn parallel(self, other: Self) -> Self {
+        self.append(Position::WithPrevious, other)
+    }
+
+    pub fn duration(&self) -> time::Duration {
+        self.entries
+            .iter()
+            .map(|entry| entry.end())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Samples every key `elapsed` after the start. The most recently started
+    /// tween of a key wins; keys that have not started yet hold the `from`
+    /// of their earliest tween.
+    pub fn sample(&self, elapsed: time::Duration) -> Frame {
+        self.current(elapsed)
+            .into_iter()
+            .map(|(key, entry)| {
+                let value: f32 = if elapsed < entry.start {
+                    entry.tween.from
+                } else {
+                    entry.tween.sample(elapsed - entry.start)
+                };
+                (key, value)
+            })
+            .collect()
+    }
+
+    /// Like `sample`, but when `prefers_reduced_motion` is set, keys whose
+    /// current tween respects it hold their end state.
+    pub fn sample_with_reduced_motion(&self, elapsed: time::Duration, prefers_reduced_motion: bool) -> Frame {
+        let mut frame: Frame = self.sample(elapsed);
+        if !prefers_reduced_motion {
+            return frame
+        }
+        let end: Frame = self.sample(self.duration());
+        for (key, entry) in self.current(elapsed) {
+            if self.respects(entry) && let Some(value) = end.get(key) {
+                frame.insert(key, *value);
+            }
+        }
+        frame
+    }
+
+    /// The tween of every key at `elapsed`.
+    fn current(&self, elapsed: time::Duration) -> HashMap<&'static str, &Entry> {
+        let mut current: HashMap<&'static str, &Entry> = HashMap::new();
+        for entry in self.entries.iter() {
+            let Some(existing) = current.get(entry.key) else {
+                current.insert(entry.key, entry);
+                continue
+            };
+            let started: bool = entry.start <= elapsed;
+            let replaces: bool = if started {
+                existing.start > elapsed || entry.start >= existing.start
+            } else {
+                existing.start > elapsed && entry.start < existing.start
+            };
+            if replaces {
+                current.insert(entry.key, entry);
+            }
+        }
+        current
+    }
+
+    /// Samples the timeline at `progress` through its duration, from `0.0`
+    /// to `1.0`.
+    pub fn sample_progress(&self, progress: f64) -> Frame {
+        let progress: f64 = match progress.is_finite() {
+            true => progress.clamp(0.0, 1.0),
+            false => 0.0
+        };
+        self.sample(self.duration().mul_f64(progress))
+    }
+
+    fn resolve(&self, position: &Position) -> time::Duration {
+        match position {
+            Position::End => self.duration(),
+            Position::WithPrevious => self.previous_start,
+            Position::At(at) => *at,
+            Position::Label(name) => self.labels.get(name).copied().unwrap_or_else(|| self.duration()),
+            Position::After(gap) => self.duration() + *gap,
+            Position::Before(overlap) => self.duration().saturating_sub(*overlap)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(n: u64) -> time::Duration {
+        time::Duration::from_millis(n)
+    }
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    fn fade() -> Tween {
+        Tween::new(0.0, 1.0, ms(100), easing::linear)
+    }
+
+    #[test]
+    fn sequence_and_parallel() {
+        let timeline: Timeline = Timeline::default()
+            .then("a", fade())
+            .with("b", fade())
+            .then("c", fade());
+        assert_eq!(timeline.duration(), ms(200));
+        let frame: Frame = timeline.sample(ms(50));
+        assert!(close(frame["a"], 0.5));
+        assert!(close(frame["b"], 0.5));
+        assert!(close(frame["c"], 0.0));
+        let frame: Frame = timeline.sample(ms(150));
+        assert!(close(frame["a"], 1.0));
+        assert!(close(frame["c"], 0.5));
+    }
+
+    #[test]
+    fn stagger_labels_and_offsets() {
+        let timeline: Timeline = Timeline::default()
+            .then("title", fade())
+            .label("cards")
+            .add(Position::Before(ms(50)), "subtitle", fade())
+            .stagger(Position::Label("cards"), &["x", "y"], fade(), ms(20));
+        assert_eq!(timeline.duration(), ms(220));
+        let frame: Frame = timeline.sample(ms(140));
+        assert!(close(frame["subtitle"], 0.9));
+        assert!(close(frame["x"], 0.4));
+        assert!(close(frame["y"], 0.2));
+    }
+
+    #[test]
+    fn later_tween_of_the_same_key_wins() {
+        let timeline: Timeline = Timeline::default()
+            .then("a", fade())
+            .then("a", Tween::new(1.0, 0.0, ms(100), easing::linear));
+        assert!(close(timeline.sample(ms(0))["a"], 0.0));
+        assert!(close(timeline.sample(ms(150))["a"], 0.5));
+        assert!(close(timeline.sample(ms(500))["a"], 0.0));
+    }
+
+    #[test]
+    fn non_finite_progress_samples_the_start() {
+        let timeline: Timeline = Timeline::default().then("a", fade());
+        assert!(close(timeline.sample_progress(f64::NAN)["a"], 0.0));
+        assert!(close(timeline.sample_progress(f64::NEG_INFINITY)["a"], 0.0));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use super::*;
+use ::std::time;
+
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Tween {
+    pub from: f32,
+    pub to: f32,
+    pub duration: time::Duration,
+    pub easing: easing::Easing,
+    /// Overrides the setting of the `Timeline` the tween is played in.
+    pub reduced_motion: Option<ReducedMotion>
+}
+
+impl Tween {
+    pub fn new(from: f32, to: f32, duration: time::Duration, easing: easing::Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing,
+            reduced_motion: None
+        }
+    }
+
+    pub fn reduced_motion(mut self, reduced_motion: ReducedMotion) -> Self {
+        self.reduced_motion = Some(reduced_motion);
+        self
+    }
+
+    /// Whether the tween holds its end under reduced motion on its own,
+    /// which it does unless set to `ReducedMotion::Ignore`.
+    pub fn respects_reduced_motion(&self) -> bool {
+        self.reduced_motion != Some(ReducedMotion::Ignore)
+    }
+
+    /// Like `sample`, but holds `to` when `prefers_reduced_motion` is set,
+    /// unless the tween opted out with `ReducedMotion::Ignore`.
+    pub fn sample_with_reduced_motion(&self, elapsed: time::Duration, prefers_reduced_motion: bool) -> f32 {
+        if prefers_reduced_motion && self.respects_reduced_motion() {
+            return self.to
+        }
+        self.sample(elapsed)
+    }
+
+    /// Samples the tween `elapsed` after it started. Values before the start
+    /// and after the end are held at `from` and `to` respectively.
+    pub fn sample(&self, elapsed: time::Duration) -> f32 {
+        if self.duration.is_zero() || elapsed >= self.duration {
+            return self.to
+        }
+        let p: f32 = elapsed.as_secs_f32();
+        let d: f32 = self.duration.as_secs_f32();
+        (self.easing)(p, self.from, self.to - self.from, d)
+    }
+
+    /// Samples the tween at `progress` through it, from `0.0` to `1.0`, so it
+    /// can be driven by something other than time, such as scroll position.
+    pub fn sample_progress(&self, progress: f64) -> f32 {
+        let progress: f64 = match progress.is_finite() {
+            true => progress.clamp(0.0, 1.0),
+            false => 0.0
+        };
+        self.sample(self.duration.mul_f64(progress))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn non_finite_progress_samples_the_start() {
+        let tween: Tween = Tween::new(0.0, 10.0, time::Duration::from_millis(100), easing::linear);
+        assert_eq!(tween.sample_progress(f64::NAN), 0.0);
+        assert_eq!(tween.sample_progress(f64::INFINITY), 0.0);
+        assert_eq!(tween.sample_progress(2.0), 10.0);
+    }
+
+    #[test]
+    fn reduced_motion_holds_the_end_value_unless_ignored() {
+        let tween: Tween = Tween::new(0.0, 10.0, time::Duration::from_millis(100), easing::linear);
+        let halfway: time::Duration = time::Duration::from_millis(50);
+        assert_eq!(tween.sample_with_reduced_motion(halfway, true), 10.0);
+        assert_eq!(tween.sample_with_reduced_motion(halfway, false), 5.0);
+        let tween: Tween = tween.reduced_motion(ReducedMotion::Ignore);
+        assert_eq!(tween.sample_with_reduced_motion(halfway, true), 5.0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,200 @@
+use super::*;
+use win::Breakpoint;
+use win::Breakpoints;
+use win::Device;
+
+/// A value that changes with the breakpoint `win::use_device` matches.
+///
+/// The device fields override the default `win::Breakpoints`, and `at`
+/// overrides any breakpoint by name. Overrides cascade upwards: a breakpoint
+/// without its own value takes the value of the nearest narrower one that has
+/// one, and `base` otherwise. Breakpoints limited to an orientation only
+/// apply to themselves.
+///
+/// # Example
+/// ```rs
+/// rsx!(
+///     Grid {
+///         gap: Responsive::new(String::from("8px"))
+///             .tablet(String::from("16px"))
+///             .laptop_l(String::from("32px")),
+///     }
+/// )
+/// ```
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct Responsive<T> {
+    pub base: T,
+    pub mobile_s: Option<T>,
+    pub mobile_m: Option<T>,
+    pub mobile_l: Option<T>,
+    pub tablet: Option<T>,
+    pub laptop_s: Option<T>,
+    pub laptop_l: Option<T>,
+    pub laptop_4k: Option<T>,
+    pub custom: Vec<(String, T)>
+}
+
+impl<T> Responsive<T> {
+    pub fn new(base: T) -> Self {
+        Self {
+            base,
+            mobile_s: None,
+            mobile_m: None,
+            mobile_l: None,
+            tablet: None,
+            laptop_s: None,
+            laptop_l: None,
+            laptop_4k: None,
+            custom: Vec::new()
+        }
+    }
+
+    pub fn mobile_s(mut self, value: T) -> Self {
+        self.mobile_s = Some(value);
+        self
+    }
+
+    pub fn mobile_m(mut self, value: T) -> Self {
+        self.mobile_m = Some(value);
+        self
+    }
+
+    pub fn mobile_l(mut self, value: T) -> Self {
+        self.mobile_l = Some(value);
+        self
+    }
+
+    pub fn tablet(mut self, value: T) -> Self {
+        self.tablet = Some(value);
+        self
+    }
+
+    pub fn laptop_s(mut self, value: T) -> Self {
+        self.laptop_s = Some(value);
+        self
+    }
+
+    pub fn laptop_l(mut self, value: T) -> Self {
+        self.laptop_l = Some(value);
+        self
+    }
+
+    pub fn laptop_4k(mut self, value: T) -> Self {
+        self.laptop_4k = Some(value);
+        self
+    }
+
+    /// Overrides the breakpoint called `name`.
+    pub fn at(mut self, name: &str, value: T) -> Self {
+        self.custom.push((name.to_owned(), value));
+        self
+    }
+
+    /// The override for the breakpoint called `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&T> {
+        if let Some((_, value)) = self.custom.iter().rev().find(|(other, _)| other == name) {
+            return Some(value)
+        }
+        [
+            (Device::MobileS, self.mobile_s.as_ref()),
+            (Device::MobileM, self.mobile_m.as_ref()),
+            (Device::MobileL, self.mobile_l.as_ref()),
+            (Device::Tablet, self.tablet.as_ref()),
+            (Device::LaptopS, self.laptop_s.as_ref()),
+            (Device::LaptopL, self.laptop_l.as_ref()),
+            (Device::Laptop4K, self.laptop_4k.as_ref())
+        ]
+            .into_iter()
+            .find(|(device, _)| device.name() == name)
+            .and_then(|(_, value)| value)
+    }
+
+    pub fn resolve(&self, breakpoint: &Breakpoint, breakpoints: &Breakpoints) -> &T {
+        breakpoints
+            .ascending()
+            .into_iter()
+            .filter(|other| {
+                other.min_w <= breakpoint.min_w
+                    && other.name != breakpoint.name
+                    && other.orientation.is_none()
+            })
+            .chain(::std::iter::once(breakpoint))
+            .filter_map(|other| self.get(&other.name))
+            .last()
+            .unwrap_or(&self.base)
+    }
+
+    /// Resolves against the output of `win::use_device`, falling back to
+    /// `base` until a breakpoint matches.
+    pub fn current(&self, device: &Option<win::Result<Breakpoint>>, breakpoints: &Breakpoints) -> &T {
+        match device {
+            Some(Ok(breakpoint)) => self.resolve(breakpoint, breakpoints),
+            _ => &self.base
+        }
+    }
+}
+
+impl<T> Responsive<T>
+where
+    T: ::std::fmt::Display {
+    /// Scoped styles setting `property`, with one media query per
+    /// overridden breakpoint.
+    pub fn scoped(&self, property: style::Property, breakpoints: &Breakpoints) -> stylesheet::Scoped {
+        let mut scoped: stylesheet::Scoped = stylesheet::Scoped::new(style::Style::default().set(property.to_owned(), &self.base));
+        for breakpoint in breakpoints.ascending() {
+            if let Some(value) = self.get(&breakpoint.name) {
+                scoped = scoped.media(&breakpoint.media(), style::Style::default().set(property.to_owned(), value));
+            }
+        }
+        scoped
+    }
+}
+
+impl<T> From<T> for Responsive<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[doc(hidden)]
+pub struct OptionResponsiveFromMarker;
+
+impl SuperFrom<unit::Unit<unit::Relative>, OptionResponsiveFromMarker> for Option<Responsive<unit::Unit<unit::Relative>>> {
+    fn super_from(input: unit::Unit<unit::Relative>) -> Self {
+        Some(Responsive::new(input))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn overrides_cascade_upwards() {
+        let breakpoints: Breakpoints = Breakpoints::default();
+        let gap: Responsive<u8> = Responsive::new(1).tablet(2).laptop_l(3);
+        let at = |device: Device| *gap.resolve(&device.into(), &breakpoints);
+        assert_eq!(at(Device::MobileL), 1);
+        assert_eq!(at(Device::LaptopS), 2);
+        assert_eq!(at(Device::Laptop4K), 3);
+        assert_eq!(*gap.current(&None, &breakpoints), 1);
+        let scoped: stylesheet::Scoped = gap.scoped(style::Property::Gap, &breakpoints);
+        assert_eq!(scoped.css(), format!(
+            ".{0} {{ gap: 1; }}\n@media (min-width: 768px) {{ .{0} {{ gap: 2; }} }}\n@media (min-width: 1440px) {{ .{0} {{ gap: 3; }} }}",
+            scoped.class()
+        ));
+    }
+
+    #[test]
+    fn custom_breakpoints() {
+        let breakpoints: Breakpoints = Breakpoints(vec!(
+            Breakpoint::new("phone", 0.0),
+            Breakpoint::new("desktop", 900.0)
+        ));
+        let size: Responsive<u8> = Responsive::new(1).at("desktop", 2);
+        assert_eq!(*size.resolve(&breakpoints.0[0], &breakpoints), 1);
+        assert_eq!(*size.resolve(&breakpoints.0[1], &breakpoints), 2);
+    }
+}
\ No newline at end of file
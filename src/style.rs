@@ -0,0 +1,287 @@
+use super::*;
+
+/// A CSS property. Anything without a variant goes through `Custom`, which is
+/// also what unknown names parse to.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Property {
+    Display,
+    Position,
+    Top,
+    Right,
+    Bottom,
+    Left,
+    Width,
+    MinWidth,
+    MaxWidth,
+    Height,
+    MinHeight,
+    MaxHeight,
+    Margin,
+    MarginBottom,
+    Padding,
+    Overflow,
+    OverflowX,
+    OverflowY,
+    PointerEvents,
+    ZIndex,
+    Background,
+    BackgroundClip,
+    Color,
+    Flex,
+    FlexDirection,
+    FlexWrap,
+    FlexGrow,
+    FlexShrink,
+    FlexBasis,
+    JustifyContent,
+    AlignItems,
+    AlignSelf,
+    Gap,
+    RowGap,
+    ColumnGap,
+    GridTemplateRows,
+    GridTemplateColumns,
+    GridTemplateAreas,
+    GridAutoFlow,
+    GridAutoRows,
+    GridAutoColumns,
+    GridArea,
+    GridColumnStart,
+    GridColumnEnd,
+    GridRowStart,
+    GridRowEnd,
+    ScrollSnapType,
+    ScrollSnapAlign,
+    ScrollBehavior,
+    FontSize,
+    FontFamily,
+    FontWeight,
+    FontStyle,
+    FontVariationSettings,
+    FontFeatureSettings,
+    LineHeight,
+    LetterSpacing,
+    WordSpacing,
+    TextTransform,
+    TextDecoration,
+    TextDecorationLine,
+    TextDecorationStyle,
+    TextDecorationColor,
+    TextDecorationThickness,
+    TextShadow,
+    TextAlign,
+    TextWrap,
+    TextOverflow,
+    TextOrientation,
+    WritingMode,
+    WhiteSpace,
+    OverflowWrap,
+    LineClamp,
+    #[strum(serialize = "-webkit-background-clip")]
+    WebkitBackgroundClip,
+    #[strum(serialize = "-webkit-text-fill-color")]
+    WebkitTextFillColor,
+    #[strum(serialize = "-webkit-text-stroke")]
+    WebkitTextStroke,
+    #[strum(serialize = "-webkit-line-clamp")]
+    WebkitLineClamp,
+    #[strum(serialize = "-webkit-box-orient")]
+    WebkitBoxOrient,
+    #[strum(default)]
+    Custom(String)
+}
+
+/// An ordered set of CSS declarations where setting a property again
+/// replaces its earlier value.
+///
+/// # Example
+/// ```rs
+/// let style: Style = Style::default()
+///     .set(Property::Display, "flex")
+///     .set(Property::Gap, gap)
+///     .merge(props.style.unwrap_or_default());
+/// ```
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct Style(Vec<(Property, String)>);
+
+impl Style {
+    pub fn set<T>(mut self, property: Property, value: T) -> Self
+    where
+        T: ::std::fmt::Display {
+        self.insert(property, value.to_string());
+        self
+    }
+
+    /// Sets `property` only when there is a `value`.
+    pub fn maybe<T>(self, property: Property, value: Option<T>) -> Self
+    where
+        T: ::std::fmt::Display {
+        match value {
+            Some(value) => self.set(property, value),
+            None => self
+        }
+    }
+
+    /// Applies every declaration of `other` on top of this one.
+    pub fn merge<T>(mut self, other: T) -> Self
+    where
+        T: Into<Self> {
+        for (property, value) in other.into().0 {
+            self.insert(property, value);
+        }
+        self
+    }
+
+    pub fn get(&self, property: &Property) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(other, _)| other == property)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn remove(mut self, property: &Property) -> Self {
+        self.0.retain(|(other, _)| other != property);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(Property, String)> {
+        self.0.iter()
+    }
+
+    fn insert(&mut self, property: Property, value: String) {
+        self.0.retain(|(other, _)| other != &property);
+        self.0.push((property, value));
+    }
+}
+
+/// Parses `property: value;` declarations. Semicolons inside parentheses
+/// or quotes, such as in `url(data:...;base64,...)`, do not end a
+/// declaration. Declarations without a colon are dropped.
+impl From<&str> for Style {
+    fn from(value: &str) -> Self {
+        let mut style: Style = Style::default();
+        for declaration in declarations(value) {
+            let Some((property, value)) = declaration.split_once(':') else {
+                continue
+            };
+            let property: Property = property
+                .trim()
+                .parse()
+                .unwrap_or_else(|_| Property::Custom(property.trim().to_owned()));
+            let value: &str = value.trim();
+            if !value.is_empty() {
+                style.insert(property, value.to_owned());
+            }
+        }
+        style
+    }
+}
+
+impl From<String> for Style {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+#[doc(hidden)]
+pub struct OptionStyleFromMarker;
+
+impl<'a> SuperFrom<&'a str, OptionStyleFromMarker> for Option<Style> {
+    fn super_from(input: &'a str) -> Self {
+        Some(Style::from(input))
+    }
+}
+
+impl SuperFrom<String, OptionStyleFromMarker> for Option<Style> {
+    fn super_from(input: String) -> Self {
+        Some(Style::from(input))
+    }
+}
+
+impl<'a> SuperFrom<::std::fmt::Arguments<'a>, OptionStyleFromMarker> for Option<Style> {
+    fn super_from(input: ::std::fmt::Arguments<'a>) -> Self {
+        Some(Style::from(input.to_string()))
+    }
+}
+
+impl ::std::fmt::Display for Style {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let declarations: Vec<String> = self.0
+            .iter()
+            .map(|(property, value)| format!("{}: {};", property, value))
+            .collect();
+        write!(f, "{}", declarations.join(" "))
+    }
+}
+
+/// Splits `source` on the semicolons outside parentheses and quotes.
+fn declarations(source: &str) -> Vec<&str> {
+    let mut declarations: Vec<&str> = Vec::new();
+    let mut depth: usize = 0;
+    let mut quote: Option<char> = None;
+    let mut escaped: bool = false;
+    let mut start: usize = 0;
+    for (at, c) in source.char_indices() {
+        if escaped {
+            escaped = false;
+            continue
+        }
+        match (quote, c) {
+            (_, '\\') => escaped = true,
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {},
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&source[start..at]);
+                start = at + 1;
+            },
+            _ => {}
+        }
+    }
+    declarations.push(&source[start..]);
+    declarations
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn later_declarations_win() {
+        let style: Style = Style::default()
+            .set(Property::Display, "flex")
+            .set(Property::Gap, "1px")
+            .merge("gap: 2px; --accent: red; -webkit-line-clamp: 3")
+            .set(Property::Display, "grid");
+        assert_eq!(style.get(&Property::Gap), Some("2px"));
+        assert_eq!(style.get(&Property::Custom(String::from("--accent"))), Some("red"));
+        assert_eq!(style.to_string(), "gap: 2px; --accent: red; -webkit-line-clamp: 3; display: grid;");
+    }
+
+    #[test]
+    fn semicolons_in_urls_and_strings_stay_in_their_declaration() {
+        let style: Style = Style::from("background: url(data:image/png;base64,AAAA) no-repeat; --label: \"a; b\"; color: red");
+        assert_eq!(style.get(&Property::Background), Some("url(data:image/png;base64,AAAA) no-repeat"));
+        assert_eq!(style.get(&Property::Custom(String::from("--label"))), Some("\"a; b\""));
+        assert_eq!(style.get(&Property::Color), Some("red"));
+    }
+}
\ No newline at end of file
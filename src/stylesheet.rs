@@ -0,0 +1,301 @@
+use super::*;
+use ::std::cell::RefCell;
+use ::std::collections::HashSet;
+use ::std::rc::Rc;
+
+/// Id of the `<style>` holding registered rules. Server-rendered markup can
+/// use it so the browser appends to the same element.
+pub static STYLE_ELEMENT_ID: &str = "diogen-stylesheet";
+
+/// A named `@keyframes` rule. Names are global, so pick ones unlikely to
+/// collide.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Keyframes {
+    pub name: String,
+    pub frames: Vec<(String, style::Style)>
+}
+
+impl Keyframes {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            frames: Vec::new()
+        }
+    }
+
+    /// Adds the frame at `offset`, such as `"from"` or `"50%"`.
+    pub fn frame(mut self, offset: &str, style: style::Style) -> Self {
+        self.frames.push((offset.to_owned(), style));
+        self
+    }
+}
+
+impl ::std::fmt::Display for Keyframes {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let frames: Vec<String> = self.frames
+            .iter()
+            .map(|(offset, style)| format!("{} {{ {} }}", offset, style))
+            .collect();
+        write!(f, "@keyframes {} {{ {} }}", self.name, frames.join(" "))
+    }
+}
+
+/// Styles scoped to a generated class, including what inline styles cannot
+/// express: states, pseudo-elements, media queries and keyframes.
+///
+/// # Example
+/// ```rs
+/// let class: String = scoped(
+///     Scoped::new(Style::default().set(Property::Color, "gray"))
+///         .hover(Style::default().set(Property::Color, "black"))
+///         .pseudo("::after", Style::default().set(Property::Custom("content".into()), "'→'"))
+///         .media("(prefers-color-scheme: dark)", Style::default().set(Property::Color, "white"))
+/// );
+/// rsx!(a { class, "Next" })
+/// ```
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct Scoped {
+    pub base: style::Style,
+    /// Selector suffixes, such as `":hover"` or `" > li"`, and their styles.
+    pub nested: Vec<(String, style::Style)>,
+    /// Media queries and the styles of the class within them.
+    pub media: Vec<(String, style::Style)>,
+    pub keyframes: Vec<Keyframes>
+}
+
+impl Scoped {
+    pub fn new(base: style::Style) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+
+    pub fn pseudo(mut self, suffix: &str, style: style::Style) -> Self {
+        match self.nested.iter_mut().find(|(other, _)| other == suffix) {
+            Some((_, existing)) => *existing = existing.to_owned().merge(style),
+            None => self.nested.push((suffix.to_owned(), style))
+        }
+        self
+    }
+
+    pub fn hover(self, style: style::Style) -> Self {
+        self.pseudo(":hover", style)
+    }
+
+    pub fn focus(self, style: style::Style) -> Self {
+        self.pseudo(":focus", style)
+    }
+
+    pub fn focus_visible(self, style: style::Style) -> Self {
+        self.pseudo(":focus-visible", style)
+    }
+
+    pub fn active(self, style: style::Style) -> Self {
+        self.pseudo(":active", style)
+    }
+
+    pub fn media(mut self, query: &str, style: style::Style) -> Self {
+        match self.media.iter_mut().find(|(other, _)| other == query) {
+            Some((_, existing)) => *existing = existing.to_owned().merge(style),
+            None => self.media.push((query.to_owned(), style))
+        }
+        self
+    }
+
+    pub fn keyframes(mut self, keyframes: Keyframes) -> Self {
+        self.keyframes.push(keyframes);
+        self
+    }
+
+    /// Applies `other` on top of this definition, merging the styles of
+    /// matching selectors and queries.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.base = self.base.merge(other.base);
+        for (suffix, style) in other.nested {
+            self = self.pseudo(&suffix, style);
+        }
+        for (query, style) in other.media {
+            self = self.media(&query, style);
+        }
+        self.keyframes.extend(other.keyframes);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_empty() && self.nested.is_empty() && self.media.is_empty() && self.keyframes.is_empty()
+    }
+
+    /// A class name derived from the definition alone, so the same styles
+    /// get the same class on the server and in the browser.
+    pub fn class(&self) -> String {
+        format!("diogen-{:016x}", fnv1a(&self.css_for("&")))
+    }
+
+    /// The rules of this definition under `.{class}`.
+    pub fn css(&self) -> String {
+        self.css_for(&format!(".{}", self.class()))
+    }
+
+    fn css_for(&self, selector: &str) -> String {
+        let mut rules: Vec<String> = Vec::new();
+        if !self.base.is_empty() {
+            rules.push(format!("{} {{ {} }}", selector, self.base));
+        }
+        for (suffix, style) in self.nested.iter() {
+            rules.push(format!("{}{} {{ {} }}", selector, suffix, style));
+        }
+        for (query, style) in self.media.iter() {
+            rules.push(format!("@media {} {{ {} {{ {} }} }}", query, selector, style));
+        }
+        for keyframes in self.keyframes.iter() {
+            rules.push(keyframes.to_string());
+        }
+        rules.join("\n")
+    }
+}
+
+/// Every registered rule, once.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct Sheet {
+    classes: HashSet<String>,
+    rules: Vec<String>
+}
+
+impl Sheet {
+    /// Adds `scoped` unless its class is already present, returning the
+    /// class and, when new, its rules.
+    pub fn insert(&mut self, scoped: &Scoped) -> (String, Option<String>) {
+        let class: String = scoped.class();
+        if !self.classes.insert(class.to_owned()) {
+            return (class, None)
+        }
+        let css: String = scoped.css();
+        self.rules.push(css.to_owned());
+        (class, Some(css))
+    }
+
+    pub fn contains(&self, class: &str) -> bool {
+        self.classes.contains(class)
+    }
+}
+
+impl ::std::fmt::Display for Sheet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{}", self.rules.join("\n"))
+    }
+}
+
+/// The sheet of one `VirtualDom`, kept in its root scope.
+#[derive(Clone)]
+struct Shared(Rc<RefCell<Sheet>>);
+
+fn shared() -> Shared {
+    try_consume_context::<Shared>()
+        .unwrap_or_else(|| provide_root_context(Shared(Rc::new(RefCell::new(Sheet::default())))))
+}
+
+/// Registers `scoped` with the sheet of the current `VirtualDom` and returns
+/// its class. In the browser new rules are appended to a single `<style>` in
+/// the head.
+pub fn register(scoped: &Scoped) -> String {
+    let (class, css): (String, Option<String>) = shared().0.borrow_mut().insert(scoped);
+    #[cfg(target_arch = "wasm32")]
+    if let Some(css) = css {
+        inject(&css);
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = css;
+    class
+}
+
+/// Every rule registered while rendering `dom`, for a `<style>` in
+/// server-rendered markup.
+pub fn render(dom: &VirtualDom) -> String {
+    dom.in_runtime(|| ScopeId::ROOT.consume_context::<Shared>())
+        .map(|shared| shared.0.borrow().to_string())
+        .unwrap_or_default()
+}
+
+/// Registers `scoped` while rendering a component and returns its class.
+pub fn scoped(scoped: Scoped) -> String {
+    register(&scoped)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn inject(css: &str) {
+    let Some(doc) = ::web_sys::window().and_then(|win| win.document()) else {
+        ::web_sys::console::error_1(&"[FAULT]: Document unavailable. Scoped styles not injected.".into());
+        return
+    };
+    let element: Option<::web_sys::Element> = match doc.get_element_by_id(STYLE_ELEMENT_ID) {
+        Some(element) => Some(element),
+        None => doc
+            .create_element("style")
+            .ok()
+            .and_then(|element| {
+                element.set_id(STYLE_ELEMENT_ID);
+                let head: ::web_sys::Element = doc.query_selector("head").ok().flatten()?;
+                head.insert_adjacent_element("beforeend", &element).ok()?;
+                Some(element)
+            })
+    };
+    let injected: bool = element
+        .map(|element| element.insert_adjacent_text("beforeend", &format!("{}\n", css)).is_ok())
+        .unwrap_or_default();
+    if !injected {
+        ::web_sys::console::error_1(&"[FAULT]: Stylesheet unavailable. Scoped styles not injected.".into());
+    }
+}
+
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash: u64, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn scoped() -> Scoped {
+        Scoped::new(style::Style::default().set(style::Property::Color, "gray"))
+            .hover(style::Style::default().set(style::Property::Color, "black"))
+            .media("(min-width: 768px)", style::Style::default().set(style::Property::Gap, "8px"))
+    }
+
+    #[test]
+    fn classes_are_deterministic_and_deduplicated() {
+        let class: String = scoped().class();
+        assert_eq!(class, scoped().class());
+        assert_ne!(class, Scoped::default().class());
+        assert_eq!(scoped().css(), format!(
+            ".{0} {{ color: gray; }}\n.{0}:hover {{ color: black; }}\n@media (min-width: 768px) {{ .{0} {{ gap: 8px; }} }}",
+            class
+        ));
+        let mut sheet: Sheet = Sheet::default();
+        assert!(sheet.insert(&scoped()).1.is_some());
+        assert!(sheet.insert(&scoped()).1.is_none());
+        assert_eq!(sheet.to_string(), scoped().css());
+    }
+
+    #[test]
+    fn every_virtual_dom_has_its_own_sheet() {
+        fn app() -> Element {
+            let class: String = super::scoped(scoped());
+            rsx!(div { class })
+        }
+        let mut dom: VirtualDom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        assert_eq!(render(&dom), scoped().css());
+        assert_eq!(render(&VirtualDom::new(app)), "");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,214 @@
+use super::*;
+
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "lowercase")]
+pub enum ColorScheme {
+    #[default]
+    Light,
+    Dark
+}
+
+impl ColorScheme {
+    pub fn toggled(&self) -> Self {
+        match self {
+            Self::Light => Self::Dark,
+            Self::Dark => Self::Light
+        }
+    }
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Palette {
+    pub background: color::Color,
+    pub surface: color::Color,
+    pub text: color::Color,
+    pub muted: color::Color,
+    pub primary: color::Color,
+    pub on_primary: color::Color,
+    pub accent: color::Color,
+    pub error: color::Color
+}
+
+impl Palette {
+    pub fn light() -> Self {
+        Self {
+            background: color::Color::from_hex(0xffffff),
+            surface: color::Color::from_hex(0xf4f4f5),
+            text: color::Color::from_hex(0x18181b),
+            muted: color::Color::from_hex(0x71717a),
+            primary: color::Color::from_hex(0x2563eb),
+            on_primary: color::Color::from_hex(0xffffff),
+            accent: color::Color::from_hex(0x7c3aed),
+            error: color::Color::from_hex(0xdc2626)
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            background: color::Color::from_hex(0x09090b),
+            surface: color::Color::from_hex(0x18181b),
+            text: color::Color::from_hex(0xfafafa),
+            muted: color::Color::from_hex(0xa1a1aa),
+            primary: color::Color::from_hex(0x60a5fa),
+            on_primary: color::Color::from_hex(0x09090b),
+            accent: color::Color::from_hex(0xa78bfa),
+            error: color::Color::from_hex(0xf87171)
+        }
+    }
+}
+
+/// Steps of space for gaps and padding, from the smallest to the largest.
+/// Layouts leave `gap` unset by default; pass a step to opt in, such as
+/// `gap: unit::Length::from(theme.read().spacing.m.to_owned())`.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Spacing {
+    pub xs: unit::Unit<unit::Relative>,
+    pub s: unit::Unit<unit::Relative>,
+    pub m: unit::Unit<unit::Relative>,
+    pub l: unit::Unit<unit::Relative>,
+    pub xl: unit::Unit<unit::Relative>
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self {
+            xs: unit::Relative::Rem.into_unit(0.25),
+            s: unit::Relative::Rem.into_unit(0.5),
+            m: unit::Relative::Rem.into_unit(1.0),
+            l: unit::Relative::Rem.into_unit(2.0),
+            xl: unit::Relative::Rem.into_unit(4.0)
+        }
+    }
+}
+
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Radii {
+    pub s: unit::Unit<unit::Absolute>,
+    pub m: unit::Unit<unit::Absolute>,
+    pub l: unit::Unit<unit::Absolute>,
+    pub full: unit::Unit<unit::Absolute>
+}
+
+impl Default for Radii {
+    fn default() -> Self {
+        Self {
+            s: unit::Absolute::Px.into_unit(4.0),
+            m: unit::Absolute::Px.into_unit(8.0),
+            l: unit::Absolute::Px.into_unit(16.0),
+            full: unit::Absolute::Px.into_unit(9999.0)
+        }
+    }
+}
+
+/// Values diogen components fall back to inside a `ThemeProvider`.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Theme {
+    pub scheme: ColorScheme,
+    pub light: Palette,
+    pub dark: Palette,
+    /// The default `font-family` of `Typography`.
+    pub family: Option<String>,
+    pub type_scale: typography::TypeScale,
+    pub spacing: Spacing,
+    pub radii: Radii,
+    pub breakpoints: win::Breakpoints
+}
+
+impl Theme {
+    /// The palette of the current `scheme`.
+    pub fn palette(&self) -> &Palette {
+        match self.scheme {
+            ColorScheme::Light => &self.light,
+            ColorScheme::Dark => &self.dark
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            scheme: ColorScheme::default(),
+            light: Palette::light(),
+            dark: Palette::dark(),
+            family: None,
+            type_scale: typography::TypeScale::default(),
+            spacing: Spacing::default(),
+            radii: Radii::default(),
+            breakpoints: win::Breakpoints::default()
+        }
+    }
+}
+
+/// The theme of the closest `ThemeProvider`, or `None` outside one. Write to
+/// it to switch schemes at runtime.
+pub fn use_theme() -> Option<Signal<Theme>> {
+    try_use_context::<Signal<Theme>>()
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct ThemeProviderProps {
+    pub theme: Option<Theme>,
+    pub children: Option<Element>
+}
+
+/// Provides a theme to `use_theme` and to diogen components below it. The
+/// type scale and breakpoints are also provided on their own, and follow
+/// both changes to `theme` and writes to the provided signal.
+///
+/// # Example
+/// ```rs
+/// let theme: Option<Signal<Theme>> = use_theme();
+/// rsx!(
+///     button {
+///         onclick: move |_| {
+///             if let Some(mut theme) = theme {
+///                 let scheme: ColorScheme = theme.read().scheme.toggled();
+///                 theme.write().scheme = scheme;
+///             }
+///         },
+///         "Toggle"
+///     }
+/// )
+/// ```
+#[component]
+pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
+    let mut theme: Signal<Theme> = use_context_provider({
+        let theme: Theme = props.theme.to_owned().unwrap_or_default();
+        move || Signal::new(theme)
+    });
+    use_effect(use_reactive((&props.theme,), move |(next,)| {
+        let next: Theme = next.unwrap_or_default();
+        if *theme.peek() != next {
+            theme.set(next);
+        }
+    }));
+    let type_scale: typography::TypeScale = theme.read().type_scale.to_owned();
+    let breakpoints: win::Breakpoints = theme.read().breakpoints.to_owned();
+
+    rsx!(
+        typography::TypeScaleProvider {
+            scale: type_scale,
+            win::BreakpointsProvider {
+                breakpoints,
+                { props.children }
+            }
+        }
+    )
+}
\ No newline at end of file
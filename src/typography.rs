@@ -11,13 +11,70 @@ use super::*;
 #[derive(::strum_macros::EnumIter)]
 #[strum(serialize_all = "kebab-case")]
 pub enum Alignment {
+    /// The edge text starts from: left in left-to-right text, right in right-to-left text.
     #[default]
+    Start,
+    End,
     Left,
     Center,
     Justify,
     Right
 }
 
+#[repr(u8)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "lowercase")]
+pub enum Dir {
+    Ltr,
+    Rtl,
+    /// Lets the browser pick from the first strong character of the text.
+    #[default]
+    Auto
+}
+
+#[repr(u8)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum WritingMode {
+    #[default]
+    HorizontalTb,
+    VerticalRl,
+    VerticalLr,
+    SidewaysRl,
+    SidewaysLr
+}
+
+#[repr(u8)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TextOrientation {
+    #[default]
+    Mixed,
+    Upright,
+    Sideways
+}
+
 #[repr(u8)]
 #[derive(Clone)]
 #[derive(PartialEq)]
@@ -68,7 +125,118 @@ pub enum Weight {
     Normal,
     Bold,
     Bolder,
-    Lighter
+    Lighter,
+    #[strum(to_string = "{0}")]
+    Numeric(NumericWeight)
+}
+
+impl Weight {
+    /// A numeric weight, clamped to the `1..=1000` range CSS accepts.
+    pub const fn from_numeric(n: u16) -> Self {
+        Self::Numeric(NumericWeight::new(n))
+    }
+}
+
+/// A numeric font weight, only constructible within the `1..=1000` range.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(PartialEq)]
+#[derive(Debug)]
+pub struct NumericWeight(u16);
+
+impl NumericWeight {
+    pub const fn new(n: u16) -> Self {
+        Self(if n < 1 {
+            1
+        } else if n > 1000 {
+            1000
+        } else {
+            n
+        })
+    }
+
+    pub const fn get(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for NumericWeight {
+    fn default() -> Self {
+        Self(400)
+    }
+}
+
+impl ::std::fmt::Display for NumericWeight {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A variable font axis, rendered into `font-variation-settings`.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum FontAxis {
+    Weight(f32),
+    Width(f32),
+    Slant(f32),
+    Italic(f32),
+    OpticalSize(f32),
+    Custom(String, f32)
+}
+
+impl ::std::fmt::Display for FontAxis {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Weight(n) => write!(f, "\"wght\" {}", n),
+            Self::Width(n) => write!(f, "\"wdth\" {}", n),
+            Self::Slant(n) => write!(f, "\"slnt\" {}", n),
+            Self::Italic(n) => write!(f, "\"ital\" {}", n),
+            Self::OpticalSize(n) => write!(f, "\"opsz\" {}", n),
+            Self::Custom(tag, n) => write!(f, "\"{}\" {}", tag, n)
+        }
+    }
+}
+
+/// An OpenType feature, rendered into `font-feature-settings`.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum FontFeature {
+    Ligatures(bool),
+    ContextualAlternates(bool),
+    DiscretionaryLigatures(bool),
+    Kerning(bool),
+    TabularNums,
+    ProportionalNums,
+    LiningNums,
+    OldstyleNums,
+    SlashedZero,
+    Fractions,
+    SmallCaps,
+    AllSmallCaps,
+    /// One of the stylistic sets `ss01` through `ss20`.
+    StylisticSet(u8),
+    Custom(String, u32)
+}
+
+impl ::std::fmt::Display for FontFeature {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Ligatures(on) => write!(f, "\"liga\" {}", *on as u8),
+            Self::ContextualAlternates(on) => write!(f, "\"calt\" {}", *on as u8),
+            Self::DiscretionaryLigatures(on) => write!(f, "\"dlig\" {}", *on as u8),
+            Self::Kerning(on) => write!(f, "\"kern\" {}", *on as u8),
+            Self::TabularNums => write!(f, "\"tnum\" 1"),
+            Self::ProportionalNums => write!(f, "\"pnum\" 1"),
+            Self::LiningNums => write!(f, "\"lnum\" 1"),
+            Self::OldstyleNums => write!(f, "\"onum\" 1"),
+            Self::SlashedZero => write!(f, "\"zero\" 1"),
+            Self::Fractions => write!(f, "\"frac\" 1"),
+            Self::SmallCaps => write!(f, "\"smcp\" 1"),
+            Self::AllSmallCaps => write!(f, "\"smcp\" 1, \"c2sc\" 1"),
+            Self::StylisticSet(n) => write!(f, "\"ss{:02}\" 1", n.clamp(&1, &20)),
+            Self::Custom(tag, n) => write!(f, "\"{}\" {}", tag, n)
+        }
+    }
 }
 
 #[repr(u8)]
@@ -116,16 +284,458 @@ pub enum OverflowWrap {
 #[derive(::strum_macros::EnumCount)]
 #[derive(::strum_macros::IntoStaticStr)]
 #[derive(::strum_macros::EnumIter)]
-pub enum Gradient {
+#[strum(serialize_all = "kebab-case")]
+pub enum Transform {
+    #[default]
+    None,
+    Capitalize,
+    Uppercase,
+    Lowercase,
+    FullWidth
+}
+
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Shadow {
+    pub x: unit::Unit<unit::Absolute>,
+    pub y: unit::Unit<unit::Absolute>,
+    pub blur: unit::Unit<unit::Absolute>,
+    pub color: color::Color
+}
+
+impl ::std::fmt::Display for Shadow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{} {} {} {}", self.x, self.y, self.blur, self.color)
+    }
+}
+
+/// An outline drawn around each glyph through `-webkit-text-stroke`.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Stroke {
+    pub width: unit::Unit<unit::Absolute>,
+    pub color: color::Color
+}
+
+impl ::std::fmt::Display for Stroke {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{} {}", self.width, self.color)
+    }
+}
+
+/// How text that does not fit is cut off.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Truncate {
+    /// A single line ending in an ellipsis.
+    Ellipsis,
+    /// At most `n` lines, the last ending in an ellipsis.
+    Lines(u32)
+}
+
+impl From<Truncate> for style::Style {
+    fn from(value: Truncate) -> Self {
+        let style: style::Style = style::Style::default()
+            .set(style::Property::Overflow, "hidden");
+        match value {
+            Truncate::Ellipsis => style
+                .set(style::Property::WhiteSpace, "nowrap")
+                .set(style::Property::TextOverflow, "ellipsis"),
+            Truncate::Lines(n) => style
+                .set(style::Property::Display, "-webkit-box")
+                .set(style::Property::WebkitBoxOrient, "vertical")
+                .set(style::Property::WebkitLineClamp, n)
+                .set(style::Property::LineClamp, n)
+        }
+    }
+}
+
+impl ::std::fmt::Display for Truncate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{}", style::Style::from(self.to_owned()))
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TextWrap {
     #[default]
-    #[strum(serialize = "linear-gradient")]
-    Linear,
-    #[strum(serialize = "radial-gradient")]
-    Radial,
-    #[strum(serialize = "conic-gradient")]
-    Conic,
-    #[strum(serialize = "repeating-gradient")]
-    Repeating
+    Wrap,
+    Nowrap,
+    Balance,
+    Pretty,
+    Stable
+}
+
+/// Lets the reader expand truncated text, with the labels of the toggle.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Expand {
+    pub more: String,
+    pub less: String
+}
+
+impl Default for Expand {
+    fn default() -> Self {
+        Self {
+            more: String::from("Show more"),
+            less: String::from("Show less")
+        }
+    }
+}
+
+/// How the glyphs of a `Typography` are painted.
+///
+/// Gradients are clipped to the text. A gradient with a single stop
+/// falls back to a solid `color`.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum TextFill {
+    Solid(color::Color),
+    Gradient(gradient::Gradient)
+}
+
+impl Default for TextFill {
+    fn default() -> Self {
+        Self::Solid(color::Color::default())
+    }
+}
+
+impl TextFill {
+    /// The fill with any gradient mirrored; solid fills are unchanged.
+    pub fn flip_inline(&self) -> Self {
+        match self {
+            Self::Solid(color) => Self::Solid(color.to_owned()),
+            Self::Gradient(gradient) => Self::Gradient(gradient.flip_inline())
+        }
+    }
+}
+
+impl From<color::Color> for TextFill {
+    fn from(value: color::Color) -> Self {
+        Self::Solid(value)
+    }
+}
+
+impl From<gradient::Gradient> for TextFill {
+    fn from(value: gradient::Gradient) -> Self {
+        Self::Gradient(value)
+    }
+}
+
+impl From<Vec<color::Color>> for TextFill {
+    fn from(value: Vec<color::Color>) -> Self {
+        Self::Gradient(value.into())
+    }
+}
+
+impl From<TextFill> for style::Style {
+    fn from(value: TextFill) -> Self {
+        let gradient: gradient::Gradient = match value {
+            TextFill::Solid(color) => return style::Style::default().set(style::Property::Color, color),
+            TextFill::Gradient(gradient) => gradient
+        };
+        if gradient.stops.len() < 2 {
+            let color: color::Color = gradient.stops
+                .first()
+                .map(|stop| stop.color.to_owned())
+                .unwrap_or_default();
+            return style::Style::default().set(style::Property::Color, color)
+        }
+        style::Style::default()
+            .set(style::Property::Background, gradient)
+            .set(style::Property::WebkitBackgroundClip, "text")
+            .set(style::Property::WebkitTextFillColor, "transparent")
+            .set(style::Property::BackgroundClip, "text")
+            .set(style::Property::Color, "transparent")
+    }
+}
+
+impl ::std::fmt::Display for TextFill {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{}", style::Style::from(self.to_owned()))
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "lowercase")]
+pub enum Tag {
+    #[default]
+    Div,
+    H1,
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
+    P,
+    Span,
+    Label
+}
+
+/// A semantic role of a run of text, resolved against the `TypeScale` in context.
+#[repr(u8)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Variant {
+    Display,
+    Headline,
+    Title,
+    #[default]
+    Body,
+    Label,
+    Caption
+}
+
+impl Variant {
+    /// The tag a variant renders as when `Typography` is given no `tag`.
+    pub const fn tag(&self) -> Tag {
+        match self {
+            Self::Display => Tag::H1,
+            Self::Headline => Tag::H2,
+            Self::Title => Tag::H3,
+            Self::Body => Tag::P,
+            Self::Label => Tag::Label,
+            Self::Caption => Tag::Span
+        }
+    }
+}
+
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct TypeStyle {
+    pub size: unit::Unit<unit::Relative>,
+    pub weight: Weight,
+    pub line_height: f64,
+    pub letter_spacing: unit::Unit<unit::Relative>
+}
+
+impl TypeStyle {
+    fn new(size: f64, weight: Weight, line_height: f64, letter_spacing: f64) -> Self {
+        Self {
+            size: unit::Relative::Rem.into_unit(size),
+            weight,
+            line_height,
+            letter_spacing: unit::Relative::Em.into_unit(letter_spacing)
+        }
+    }
+}
+
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct TypeScale {
+    pub display: TypeStyle,
+    pub headline: TypeStyle,
+    pub title: TypeStyle,
+    pub body: TypeStyle,
+    pub label: TypeStyle,
+    pub caption: TypeStyle
+}
+
+impl TypeScale {
+    pub fn get(&self, variant: &Variant) -> &TypeStyle {
+        match variant {
+            Variant::Display => &self.display,
+            Variant::Headline => &self.headline,
+            Variant::Title => &self.title,
+            Variant::Body => &self.body,
+            Variant::Label => &self.label,
+            Variant::Caption => &self.caption
+        }
+    }
+}
+
+impl Default for TypeScale {
+    fn default() -> Self {
+        Self {
+            display: TypeStyle::new(3.5, Weight::Bold, 1.1, -0.02),
+            headline: TypeStyle::new(2.25, Weight::Bold, 1.2, 0.0),
+            title: TypeStyle::new(1.5, Weight::Bold, 1.3, 0.0),
+            body: TypeStyle::new(1.0, Weight::Normal, 1.5, 0.0),
+            label: TypeStyle::new(0.875, Weight::Bold, 1.4, 0.02),
+            caption: TypeStyle::new(0.75, Weight::Normal, 1.4, 0.02)
+        }
+    }
+}
+
+/// The `TypeScale` provided by the nearest `TypeScaleProvider`, or the default scale.
+pub fn use_type_scale() -> TypeScale {
+    try_use_context::<Signal<TypeScale>>()
+        .map(|scale| scale.read().to_owned())
+        .unwrap_or_default()
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct TypeScaleProviderProps {
+    pub scale: TypeScale,
+    pub children: Option<Element>
+}
+
+#[component]
+pub fn TypeScaleProvider(props: TypeScaleProviderProps) -> Element {
+    let mut scale: Signal<TypeScale> = use_context_provider({
+        let scale: TypeScale = props.scale.to_owned();
+        move || Signal::new(scale)
+    });
+    use_effect(use_reactive((&props.scale,), move |(next,)| {
+        if *scale.peek() != next {
+            scale.set(next);
+        }
+    }));
+
+    rsx!(
+        { props.children }
+    )
+}
+
+/// A run of inline text that inherits from its `Typography` and overrides
+/// only what is set.
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct Span {
+    pub text: String,
+    pub weight: Option<Weight>,
+    pub color: Option<color::Color>,
+    pub decor: Option<Vec<Decor>>,
+    pub href: Option<Url>,
+    pub code: bool
+}
+
+impl Span {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    pub fn color(mut self, color: color::Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn decor(mut self, decor: Vec<Decor>) -> Self {
+        self.decor = Some(decor);
+        self
+    }
+
+    pub fn href(mut self, href: Url) -> Self {
+        self.href = Some(href);
+        self
+    }
+
+    pub fn code(mut self) -> Self {
+        self.code = true;
+        self
+    }
+}
+
+impl From<&str> for Span {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for Span {
+    fn from(value: String) -> Self {
+        Self {
+            text: value,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct TextSpanProps {
+    pub weight: Option<Weight>,
+    pub color: Option<color::Color>,
+    pub decor: Option<Vec<Decor>>,
+    pub href: Option<Url>,
+    pub code: Option<bool>,
+    pub class: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
+    pub children: Option<Element>
+}
+
+/// Inline text within a `Typography`. Renders an `a` when given an `href`,
+/// a `code` when `code` is set and a `span` otherwise.
+///
+/// # Example
+/// ```rs
+/// rsx!(
+///     Typography::<Family> {
+///         "Read the "
+///         TextSpan {
+///             href: Url::External("https://example.com/terms".into()),
+///             decor: vec!(Decor::Underline),
+///             "terms"
+///         }
+///         " before running "
+///         TextSpan { code: true, "cargo publish" }
+///     }
+/// )
+/// ```
+#[component]
+pub fn TextSpan(props: TextSpanProps) -> Element {
+    let decor = props.decor.map(|decor| {
+        decor
+            .iter()
+            .map(|decor| {
+                format!("{}", decor)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+    let style: String = style::Style::default()
+        .maybe(style::Property::FontWeight, props.weight)
+        .maybe(style::Property::Color, props.color.to_owned())
+        .maybe(style::Property::WebkitTextFillColor, props.color)
+        .maybe(style::Property::TextDecorationLine, decor)
+        .merge(props.style.unwrap_or_default())
+        .to_string();
+    let class: Option<String> = props.class;
+    let children: Option<Element> = props.children;
+
+    match (props.href, props.code.unwrap_or_default()) {
+        (Some(href), true) => rsx!(a { href: href.to_string(), class, style, code { { children } } }),
+        (Some(href), false) => rsx!(a { href: href.to_string(), class, style, { children } }),
+        (None, true) => rsx!(code { class, style, { children } }),
+        (None, false) => rsx!(span { class, style, { children } })
+    }
 }
 
 #[derive(Props)]
@@ -138,12 +748,25 @@ where
     T: PartialEq,
     T: Default,
     T: ::std::fmt::Display {
-    pub size: Option<unit::Unit<unit::Relative>>,
+    pub variant: Option<Variant>,
+    /// Applied through a scoped class with one media query per overridden
+    /// breakpoint.
+    #[props(into)]
+    pub size: Option<responsive::Responsive<unit::Unit<unit::Relative>>>,
+    /// Falls back to the `theme::Theme` family inside a `ThemeProvider`.
     pub family: Option<T>,
     pub weight: Option<Weight>,
-    pub gradient: Option<Gradient>,
-    pub gradient_direction: Option<direction::Direction>,
-    pub colors: Option<Vec<color::Color>>,
+    pub font_style: Option<font::Style>,
+    /// A unitless multiple of the font size.
+    pub line_height: Option<f64>,
+    pub axes: Option<Vec<FontAxis>>,
+    pub features: Option<Vec<FontFeature>>,
+    /// Falls back to the theme's text color inside a `ThemeProvider`, and is
+    /// inherited otherwise.
+    pub fill: Option<TextFill>,
+    pub shadows: Option<Vec<Shadow>>,
+    pub stroke: Option<Stroke>,
+    pub transform: Option<Transform>,
     pub letter_spacing: Option<unit::Unit<unit::Relative>>,
     pub word_spacing: Option<unit::Unit<unit::Relative>>,
     pub decor: Option<Vec<Decor>>,
@@ -152,7 +775,24 @@ where
     pub decor_thickness: Option<unit::Unit<unit::Relative>>,
     pub white_space: Option<WhiteSpace>,
     pub overflow_wrap: Option<OverflowWrap>,
-    pub alignment: Option<Alignment>
+    pub alignment: Option<Alignment>,
+    /// The base direction of the text. Gradient fills are mirrored wherever
+    /// the resolved direction is right-to-left, including when inherited.
+    pub dir: Option<Dir>,
+    pub writing_mode: Option<WritingMode>,
+    pub text_orientation: Option<TextOrientation>,
+    pub text_wrap: Option<TextWrap>,
+    pub truncate: Option<Truncate>,
+    /// Shows a toggle after `truncate`d text when it overflows.
+    pub expand: Option<Expand>,
+    pub tag: Option<Tag>,
+    /// Inline runs rendered ahead of `children`.
+    pub spans: Option<Vec<Span>>,
+    pub id: Option<String>,
+    pub class: Option<String>,
+    #[props(into)]
+    pub style: Option<style::Style>,
+    pub children: Option<Element>
 }
 
 /// # Example
@@ -169,13 +809,15 @@ where
 /// function Main() -> Element {
 ///     rsx!(
 ///         ::diogen::typography::Typography::<Family> {
-///             size: Some(("em".into(), 1.0).into()),
-///             gradient_direction: "bottom-right".into(),
-///             colors: vec!(
-///                 0x202020.into(),
-///                 0xffffff.into()
-///             ),
-///             family: "sans-serif".into()
+///             size: Responsive::new(Relative::Em.into_unit(1.0)).tablet(Relative::Em.into_unit(1.25)),
+///             fill: Gradient::linear()
+///                 .direction(Direction::BottomRight)
+///                 .stop(Color::from_hex(0x202020))
+///                 .stop_at(Color::from_hex(0xffffff), Relative::Percentage.into_unit(80.0))
+///                 .into(),
+///             family: "sans-serif".into(),
+///             variant: Variant::Display,
+///             "Hello, world"
 ///         }
 ///     )
 /// }
@@ -188,93 +830,249 @@ where
     T: PartialEq,
     T: Default,
     T: ::std::fmt::Display {
-    let size = props.size.unwrap_or_else(|| {
-        (unit::Relative::Em, 1.0).into()
-    });
-    let family = props.family.unwrap_or_default();
-    let weight = props.weight.unwrap_or_default();
-    let gradient = props.gradient.unwrap_or_default();
-    let gradient_direction = props.gradient_direction.unwrap_or_default();
-    let colors = props.colors.unwrap_or_else(|| {
-        vec!(
-            0xffffff.try_into().unwrap()
-        )
-    });
-    let letter_spacing = props.letter_spacing.unwrap_or_else(|| {
-        (unit::Relative::Em, 1.0).into()
-    });
-    let word_spacing = props.word_spacing.unwrap_or_else(|| {
-        (unit::Relative::Em, 1.0).into()
-    });
+    let type_scale: TypeScale = use_type_scale();
+    let type_style: Option<TypeStyle> = props.variant
+        .as_ref()
+        .map(|variant| type_scale.get(variant).to_owned());
+    let breakpoints: win::Breakpoints = win::use_breakpoints();
+    let size_class: Option<String> = props.size
+        .as_ref()
+        .map(|size| stylesheet::register(&size.scoped(style::Property::FontSize, &breakpoints)));
+    let size = match props.size {
+        Some(_) => None,
+        None => type_style.as_ref().map(|type_style| type_style.size.to_owned())
+    };
+    let theme: Option<Signal<theme::Theme>> = try_use_context();
+    let family = props.family
+        .map(|family| family.to_string())
+        .or_else(|| theme.and_then(|theme| theme.read().family.to_owned()))
+        .or_else(|| Some(T::default().to_string()))
+        .filter(|family| !family.is_empty());
+    let weight = props.weight
+        .or_else(|| type_style.as_ref().map(|type_style| type_style.weight.to_owned()));
+    let axes = props.axes
+        .filter(|axes| !axes.is_empty())
+        .map(|axes| {
+            axes
+                .iter()
+                .map(|axis| {
+                    format!("{}", axis)
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+    let features = props.features
+        .filter(|features| !features.is_empty())
+        .map(|features| {
+            features
+                .iter()
+                .map(|feature| {
+                    format!("{}", feature)
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+    let fill = props.fill
+        .or_else(|| theme.map(|theme| TextFill::Solid(theme.read().palette().text.to_owned())));
+    let (fill, fill_class): (Option<TextFill>, Option<String>) = match fill {
+        Some(fill @ TextFill::Gradient(_)) => {
+            let scoped: stylesheet::Scoped = stylesheet::Scoped::new(fill.to_owned().into())
+                .pseudo(":dir(rtl)", fill.flip_inline().into());
+            (None, Some(stylesheet::register(&scoped)))
+        },
+        solid => (solid, None)
+    };
+    let line_height = props.line_height
+        .or_else(|| type_style.as_ref().map(|type_style| type_style.line_height));
+    let shadows = props.shadows
+        .filter(|shadows| !shadows.is_empty())
+        .map(|shadows| {
+            shadows
+                .iter()
+                .map(|shadow| {
+                    format!("{}", shadow)
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+    let letter_spacing = props.letter_spacing
+        .or_else(|| type_style.as_ref().map(|type_style| type_style.letter_spacing.to_owned()));
     let decor = props.decor
-        .unwrap_or_default()
-        .iter()
+        .filter(|decor| !decor.is_empty())
         .map(|decor| {
-            format!("{}", decor)
-        })
-        .collect::<Vec<_>>()
-        .join(", ");
-    let decor_color = props.decor_color.unwrap_or_default();
-    let decor_style = props.decor_style.unwrap_or_default();
-    let decor = format!("{} {} {}", decor, decor_style, decor_color);
-    let white_space = props.white_space.unwrap_or_default();
-    let overflow_wrap = props.overflow_wrap.unwrap_or_default();
-    let alignment = props.alignment.unwrap_or_default();
-    let mut background = String::new();
-    background.push_str(&format!("{}", gradient));
-    background.push('(');
-    background.push_str(match gradient_direction {
-        direction::Direction::Bottom => "to bottom",
-        direction::Direction::BottomLeft => "to left bottom",
-        direction::Direction::BottomRight => "to bottom right",
-        direction::Direction::Left => "to left",
-        direction::Direction::Right => "to right",
-        direction::Direction::Top => "to top",
-        direction::Direction::TopLeft => "to top left",
-        direction::Direction::TopRight => "to top right"
+            decor
+                .iter()
+                .map(|decor| {
+                    format!("{}", decor)
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+    let expanded: Signal<bool> = use_signal(|| false);
+    let truncate: style::Style = props.truncate
+        .filter(|_| !expanded())
+        .map(style::Style::from)
+        .unwrap_or_default();
+    let generated_id: String = use_hook(|| {
+        static NEXT_ID: ::std::sync::atomic::AtomicU64 = ::std::sync::atomic::AtomicU64::new(0);
+        let n: u64 = NEXT_ID.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed);
+        format!("diogen-typography-{}", n)
     });
-    background.push(',');
-    background.push_str(
-        &colors
-            .iter()
-            .map(|color| {
-                format!("{}", color)
-            })
-            .collect::<Vec<_>>()
-            .join(", ")
+    let style: String = style::Style::default()
+        .maybe(style::Property::FontSize, size)
+        .maybe(style::Property::FontFamily, family)
+        .maybe(style::Property::FontWeight, weight)
+        .maybe(style::Property::FontStyle, props.font_style)
+        .maybe(style::Property::FontVariationSettings, axes)
+        .maybe(style::Property::FontFeatureSettings, features)
+        .merge(fill.map(style::Style::from).unwrap_or_default())
+        .maybe(style::Property::LineHeight, line_height)
+        .maybe(style::Property::TextShadow, shadows)
+        .maybe(style::Property::WebkitTextStroke, props.stroke)
+        .maybe(style::Property::TextTransform, props.transform)
+        .maybe(style::Property::LetterSpacing, letter_spacing)
+        .maybe(style::Property::WordSpacing, props.word_spacing)
+        .maybe(style::Property::TextDecorationLine, decor)
+        .maybe(style::Property::TextDecorationStyle, props.decor_style)
+        .maybe(style::Property::TextDecorationColor, props.decor_color)
+        .maybe(style::Property::TextDecorationThickness, props.decor_thickness)
+        .maybe(style::Property::WhiteSpace, props.white_space)
+        .maybe(style::Property::OverflowWrap, props.overflow_wrap)
+        .maybe(style::Property::TextAlign, props.alignment)
+        .maybe(style::Property::WritingMode, props.writing_mode)
+        .maybe(style::Property::TextOrientation, props.text_orientation)
+        .maybe(style::Property::TextWrap, props.text_wrap)
+        .merge(truncate)
+        .merge(props.style.unwrap_or_default())
+        .to_string();
+    let id: Option<String> = match props.expand {
+        Some(_) => Some(props.id.unwrap_or(generated_id)),
+        None => props.id
+    };
+    let dir: Option<String> = props.dir.map(|dir| dir.to_string());
+    let class: Option<String> = Some([size_class, fill_class, props.class]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" "))
+        .filter(|class| !class.is_empty());
+    let spans: Vec<Span> = props.spans.unwrap_or_default();
+    let children: Element = rsx!(
+        for span in spans {
+            TextSpan {
+                weight: span.weight,
+                color: span.color,
+                decor: span.decor,
+                href: span.href,
+                code: span.code,
+                { span.text }
+            }
+        }
+        { props.children }
     );
-    background.push(')');
+
+    let tag: Tag = props.tag
+        .or_else(|| props.variant.map(|variant| variant.tag()))
+        .unwrap_or_default();
+
+    let target: Option<String> = id.to_owned();
+    let text: Element = match tag {
+        Tag::Div => rsx!(div { id, dir, class, style, { children } }),
+        Tag::H1 => rsx!(h1 { id, dir, class, style, { children } }),
+        Tag::H2 => rsx!(h2 { id, dir, class, style, { children } }),
+        Tag::H3 => rsx!(h3 { id, dir, class, style, { children } }),
+        Tag::H4 => rsx!(h4 { id, dir, class, style, { children } }),
+        Tag::H5 => rsx!(h5 { id, dir, class, style, { children } }),
+        Tag::H6 => rsx!(h6 { id, dir, class, style, { children } }),
+        Tag::P => rsx!(p { id, dir, class, style, { children } }),
+        Tag::Span => rsx!(span { id, dir, class, style, { children } }),
+        Tag::Label => rsx!(label { id, dir, class, style, { children } })
+    };
+
+    match (props.expand, target) {
+        (Some(expand), Some(target)) => rsx!(
+            div {
+                { text }
+                ExpandToggle {
+                    target,
+                    expanded,
+                    expand
+                }
+            }
+        ),
+        _ => text
+    }
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+struct ExpandToggleProps {
+    target: String,
+    expanded: Signal<bool>,
+    expand: Expand
+}
+
+#[component]
+fn ExpandToggle(props: ExpandToggleProps) -> Element {
+    let overflow: Signal<_> = win::use_element_overflow(&props.target);
+    let mut expanded: Signal<bool> = props.expanded;
+
+    if !expanded() && !matches!(overflow(), Some(Ok(true))) {
+        return rsx!()
+    }
 
     rsx!(
-        div {
-            style: format!(
-                r#"
-                    font-size: {};
-                    font-family: {};
-                    font-weight: {};
-                    background: {};
-                    -webkit-background-clip: text;
-                    -webkit-text-fill-color: transparent;
-                    background-clip: text;
-                    color: transparent;
-                    letter-spacing: {};
-                    word-spacing: {};
-                    text-decoration: {};
-                    white-space: {};
-                    overflow-wrap: {};
-                    text-align: {};
-                "#,
-                size,
-                family,
-                weight,
-                background,
-                letter_spacing,
-                word_spacing,
-                decor,
-                white_space,
-                overflow_wrap,
-                alignment
-            )
+        button {
+            r#type: "button",
+            aria_expanded: expanded(),
+            aria_controls: props.target,
+            onclick: move |_| {
+                expanded.toggle();
+            },
+            if expanded() {
+                { props.expand.less }
+            } else {
+                { props.expand.more }
+            }
         }
     )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn numeric_weights_are_clamped() {
+        assert_eq!(Weight::from_numeric(0).to_string(), "1");
+        assert_eq!(Weight::from_numeric(650).to_string(), "650");
+        assert_eq!(Weight::from_numeric(4000).to_string(), "1000");
+    }
+
+    #[test]
+    fn solid_fill_sets_color() {
+        let fill: TextFill = TextFill::Solid(color::Color::from_hex(0x202020));
+        assert_eq!(style::Style::from(fill).to_string(), "color: #202020;");
+    }
+
+    #[test]
+    fn gradient_fill_clips_background_to_text() {
+        let fill: TextFill = TextFill::Gradient(gradient::Gradient::linear()
+            .direction(direction::Direction::Right)
+            .stop(color::Color::from_hex(0x000000))
+            .stop(color::Color::from_hex(0xffffff)));
+        assert_eq!(
+            style::Style::from(fill).to_string(),
+            "background: linear-gradient(to right, #000000, #FFFFFF); -webkit-background-clip: text; -webkit-text-fill-color: transparent; background-clip: text; color: transparent;"
+        );
+    }
+
+    #[test]
+    fn gradient_fill_with_one_stop_falls_back_to_color() {
+        let fill: TextFill = TextFill::Gradient(gradient::Gradient::linear().stop(color::Color::from_hex(0xff0000)));
+        assert_eq!(style::Style::from(fill).to_string(), "color: #FF0000;");
+        let fill: TextFill = TextFill::Gradient(gradient::Gradient::linear());
+        assert_eq!(style::Style::from(fill).to_string(), "color: #FFFFFF;");
+    }
 }
\ No newline at end of file
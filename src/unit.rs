@@ -40,6 +40,44 @@ where
 }
 
 
+/// A CSS `<length>` in any of the length units.
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Length {
+    Absolute(Unit<Absolute>),
+    Relative(Unit<Relative>),
+    Viewport(Unit<Viewport>)
+}
+
+impl From<Unit<Absolute>> for Length {
+    fn from(value: Unit<Absolute>) -> Self {
+        Self::Absolute(value)
+    }
+}
+
+impl From<Unit<Relative>> for Length {
+    fn from(value: Unit<Relative>) -> Self {
+        Self::Relative(value)
+    }
+}
+
+impl From<Unit<Viewport>> for Length {
+    fn from(value: Unit<Viewport>) -> Self {
+        Self::Viewport(value)
+    }
+}
+
+impl ::std::fmt::Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Absolute(unit) => write!(f, "{}", unit),
+            Self::Relative(unit) => write!(f, "{}", unit),
+            Self::Viewport(unit) => write!(f, "{}", unit)
+        }
+    }
+}
+
+
 #[derive(Debug)]
 #[derive(Clone)]
 #[derive(PartialEq)]
@@ -85,6 +123,7 @@ pub enum Relative {
     Rem,
     Lh,
     Rlh,
+    #[strum(serialize = "%")]
     Percentage
 }
 
@@ -0,0 +1,203 @@
+use super::*;
+
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+#[derive(::strum_macros::Display)]
+#[derive(::strum_macros::EnumString)]
+#[derive(::strum_macros::EnumCount)]
+#[derive(::strum_macros::IntoStaticStr)]
+#[derive(::strum_macros::EnumIter)]
+#[strum(serialize_all = "lowercase")]
+pub enum Orientation {
+    Portrait,
+    Landscape
+}
+
+/// A named range of viewport widths, optionally limited to one orientation.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Breakpoint {
+    pub name: String,
+    pub min_w: f64,
+    /// Exclusive upper bound.
+    pub max_w: Option<f64>,
+    pub orientation: Option<Orientation>
+}
+
+impl Breakpoint {
+    pub fn new(name: &str, min_w: f64) -> Self {
+        Self {
+            name: name.to_owned(),
+            min_w,
+            max_w: None,
+            orientation: None
+        }
+    }
+
+    pub fn max_w(mut self, max_w: f64) -> Self {
+        self.max_w = Some(max_w);
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    pub fn matches(&self, w: f64, h: f64) -> bool {
+        let orientation: Orientation = if w > h {
+            Orientation::Landscape
+        } else {
+            Orientation::Portrait
+        };
+        w >= self.min_w
+            && self.max_w.is_none_or(|max_w| w < max_w)
+            && self.orientation.as_ref().is_none_or(|expected| expected == &orientation)
+    }
+
+    /// A media query matching this breakpoint and every wider one. Like
+    /// `Responsive::resolve`, a breakpoint limited to an orientation does not
+    /// carry over to wider ones, so its query keeps its own upper bound.
+    pub fn media(&self) -> String {
+        match (&self.orientation, self.max_w) {
+            (Some(orientation), Some(max_w)) => format!("(min-width: {}px) and (width < {}px) and (orientation: {})", self.min_w, max_w, orientation),
+            (Some(orientation), None) => format!("(min-width: {}px) and (orientation: {})", self.min_w, orientation),
+            (None, _) => format!("(min-width: {}px)", self.min_w)
+        }
+    }
+}
+
+impl From<Device> for Breakpoint {
+    fn from(value: Device) -> Self {
+        let breakpoint: Breakpoint = Breakpoint::new(value.name(), value.min_w());
+        match value.max_w() {
+            Some(max_w) => breakpoint.max_w(max_w),
+            None => breakpoint
+        }
+    }
+}
+
+/// The breakpoints `use_device` classifies the viewport with. The default
+/// follows `Device`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Breakpoints(pub Vec<Breakpoint>);
+
+impl Breakpoints {
+    /// The matching breakpoint with the largest `min_w`, the first declared
+    /// on ties.
+    pub fn matching(&self, w: f64, h: f64) -> Option<&Breakpoint> {
+        self.0
+            .iter()
+            .filter(|breakpoint| breakpoint.matches(w, h))
+            .fold(None, |best: Option<&Breakpoint>, breakpoint| match best {
+                Some(best) if best.min_w >= breakpoint.min_w => Some(best),
+                _ => Some(breakpoint)
+            })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Breakpoint> {
+        self.0.iter().find(|breakpoint| breakpoint.name == name)
+    }
+
+    /// The breakpoints from the narrowest to the widest.
+    pub fn ascending(&self) -> Vec<&Breakpoint> {
+        let mut breakpoints: Vec<&Breakpoint> = self.0.iter().collect();
+        breakpoints.sort_by(|a, b| a.min_w.total_cmp(&b.min_w));
+        breakpoints
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self(vec!(
+            Device::MobileS.into(),
+            Device::MobileM.into(),
+            Device::MobileL.into(),
+            Device::Tablet.into(),
+            Device::LaptopS.into(),
+            Device::LaptopL.into(),
+            Device::Laptop4K.into()
+        ))
+    }
+}
+
+pub fn use_breakpoints() -> Breakpoints {
+    try_use_context::<Signal<Breakpoints>>()
+        .map(|breakpoints| breakpoints.read().to_owned())
+        .unwrap_or_default()
+}
+
+#[derive(Props)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct BreakpointsProviderProps {
+    pub breakpoints: Breakpoints,
+    pub children: Option<Element>
+}
+
+/// # Example
+/// ```rs
+/// rsx!(
+///     BreakpointsProvider {
+///         breakpoints: Breakpoints(vec!(
+///             Breakpoint::new("phone", 0.0).max_w(600.0),
+///             Breakpoint::new("kiosk", 600.0).orientation(Orientation::Portrait),
+///             Breakpoint::new("desktop", 600.0).orientation(Orientation::Landscape)
+///         )),
+///         App {}
+///     }
+/// )
+/// ```
+#[component]
+pub fn BreakpointsProvider(props: BreakpointsProviderProps) -> Element {
+    let mut breakpoints: Signal<Breakpoints> = use_context_provider({
+        let breakpoints: Breakpoints = props.breakpoints.to_owned();
+        move || Signal::new(breakpoints)
+    });
+    use_effect(use_reactive((&props.breakpoints,), move |(next,)| {
+        if *breakpoints.peek() != next {
+            breakpoints.set(next);
+        }
+    }));
+
+    rsx!(
+        { props.children }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_breakpoints_follow_devices() {
+        let breakpoints: Breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.matching(1024.0, 800.0).map(|breakpoint| breakpoint.name.as_str()), Some("laptop-s"));
+        assert_eq!(breakpoints.matching(374.0, 800.0).map(|breakpoint| breakpoint.name.as_str()), Some("mobile-s"));
+        assert_eq!(breakpoints.matching(4000.0, 800.0).map(|breakpoint| breakpoint.name.as_str()), Some("laptop-4k"));
+    }
+
+    #[test]
+    fn orientation_narrows_the_match() {
+        let breakpoints: Breakpoints = Breakpoints(vec!(
+            Breakpoint::new("any", 0.0),
+            Breakpoint::new("portrait", 600.0).orientation(Orientation::Portrait)
+        ));
+        assert_eq!(breakpoints.matching(700.0, 900.0).map(|breakpoint| breakpoint.name.as_str()), Some("portrait"));
+        assert_eq!(breakpoints.matching(700.0, 500.0).map(|breakpoint| breakpoint.name.as_str()), Some("any"));
+    }
+
+    #[test]
+    fn oriented_media_queries_keep_their_upper_bound() {
+        assert_eq!(Breakpoint::new("a", 600.0).max_w(900.0).media(), "(min-width: 600px)");
+        assert_eq!(
+            Breakpoint::new("b", 600.0).max_w(900.0).orientation(Orientation::Portrait).media(),
+            "(min-width: 600px) and (width < 900px) and (orientation: portrait)"
+        );
+    }
+}
\ No newline at end of file
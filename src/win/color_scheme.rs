@@ -0,0 +1,156 @@
+use super::*;
+use ::wasm_bindgen::JsCast;
+use theme::ColorScheme;
+
+/// The `localStorage` key holding the user's color scheme override.
+pub static COLOR_SCHEME_STORAGE_KEY: &str = "diogen-color-scheme";
+
+/// The state behind `use_color_scheme`.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(PartialEq)]
+pub struct UseColorScheme {
+    system: Signal<Option<Result<ColorScheme>>>,
+    preference: Signal<Option<ColorScheme>>
+}
+
+impl UseColorScheme {
+    /// The user's override when there is one, the system preference otherwise.
+    pub fn scheme(&self) -> Option<Result<ColorScheme>> {
+        if let Some(preference) = *self.preference.read() {
+            return Some(Ok(preference))
+        }
+        self.system.read().to_owned()
+    }
+
+    /// The scheme asked for through `prefers-color-scheme`.
+    pub fn system(&self) -> Option<Result<ColorScheme>> {
+        self.system.read().to_owned()
+    }
+
+    pub fn preference(&self) -> Option<ColorScheme> {
+        *self.preference.read()
+    }
+
+    /// Overrides the system preference and persists the override, or
+    /// removes it with `None`.
+    pub fn set_preference(&mut self, preference: Option<ColorScheme>) -> Result<()> {
+        let storage: ::web_sys::Storage = local_storage()?;
+        match preference {
+            Some(preference) => storage.set_item(COLOR_SCHEME_STORAGE_KEY, &preference.to_string()),
+            None => storage.remove_item(COLOR_SCHEME_STORAGE_KEY)
+        }.map_err(Error::StorageAccessFailure)?;
+        self.preference.set(preference);
+        Ok(())
+    }
+}
+
+/// Follows `prefers-color-scheme`, with a user override persisted to
+/// `localStorage`, and sets `color-scheme` on the root element to match.
+/// The override is read after mounting, so the first render, like the
+/// server's, only has the system preference to go on.
+///
+/// # Example
+/// ```rs
+/// let mut color_scheme: UseColorScheme = use_color_scheme();
+/// let theme: Option<Signal<Theme>> = use_theme();
+/// use_effect(move || {
+///     if let (Some(mut theme), Some(Ok(scheme))) = (theme, color_scheme.scheme()) {
+///         theme.write().scheme = scheme;
+///     }
+/// });
+/// rsx!(
+///     button {
+///         onclick: move |_| {
+///             let _ = color_scheme.set_preference(Some(ColorScheme::Dark));
+///         },
+///         "Dark"
+///     }
+/// )
+/// ```
+pub fn use_color_scheme() -> UseColorScheme {
+    let system: Signal<Option<Result<ColorScheme>>> = use_signal(|| None);
+    let preference: Signal<Option<ColorScheme>> = use_signal(|| None);
+    let drop_token: Signal<Option<DropToken>> = use_signal(|| None);
+    let ret: UseColorScheme = UseColorScheme {
+        system,
+        preference
+    };
+
+    use_effect({
+        let mut preference: Signal<_> = preference.to_owned();
+        move || {
+            let stored: Option<ColorScheme> = local_storage()
+                .ok()
+                .and_then(|storage| storage.get_item(COLOR_SCHEME_STORAGE_KEY).ok().flatten())
+                .and_then(|stored| stored.parse().ok());
+            if stored.is_some() && *preference.peek() != stored {
+                preference.set(stored);
+            }
+        }
+    });
+
+    use_effect({
+        let mut system: Signal<_> = system.to_owned();
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            match on_media_query("(prefers-color-scheme: dark)", move |matches| {
+                system.set(Some(Ok(scheme_of(matches))));
+            }) {
+                Ok((matches, new_drop_token)) => {
+                    system.set(Some(Ok(scheme_of(matches))));
+                    drop_token.set(None);
+                    drop_token.set(Some(new_drop_token));
+                },
+                Err(e) => {
+                    system.set(Some(Err(e)));
+                }
+            }
+        }
+    });
+
+    use_effect(move || {
+        let Some(Ok(scheme)) = ret.scheme() else {
+            return
+        };
+        if let Err(e) = set_root_color_scheme(scheme) {
+            ::web_sys::console::error_1(&format!("[FAULT]: Unable to set root color-scheme: {:?}.", e).into());
+        }
+    });
+
+    use_drop({
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            drop_token.set(None);
+        }
+    });
+
+    ret
+}
+
+fn scheme_of(prefers_dark: bool) -> ColorScheme {
+    if prefers_dark {
+        ColorScheme::Dark
+    } else {
+        ColorScheme::Light
+    }
+}
+
+fn local_storage() -> Result<::web_sys::Storage> {
+    let win: ::web_sys::Window = ::web_sys::window().ok_or(Error::MissingWindow)?;
+    win.local_storage()
+        .map_err(Error::StorageAccessFailure)?
+        .ok_or(Error::MissingLocalStorage)
+}
+
+fn set_root_color_scheme(scheme: ColorScheme) -> Result<()> {
+    let win: ::web_sys::Window = ::web_sys::window().ok_or(Error::MissingWindow)?;
+    let doc: ::web_sys::Document = win.document().ok_or(Error::MissingDocument)?;
+    let root: ::web_sys::HtmlElement = doc.document_element()
+        .ok_or(Error::MissingDocumentElement)?
+        .dyn_into()
+        .map_err(|_| Error::MissingDocumentElement)?;
+    root.style()
+        .set_property("color-scheme", &scheme.to_string())
+        .map_err(Error::PropertyAccessFailure)
+}
\ No newline at end of file
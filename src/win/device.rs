@@ -1,5 +1,6 @@
 use super::*;
 
+/// The device classes of the default `Breakpoints`.
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum Device {
@@ -12,26 +13,71 @@ pub enum Device {
     MobileS
 }
 
-pub fn use_device() -> Signal<Option<Result<Device>>> {
-    let inner_w: Signal<_> = use_inner_w();
-    let mut ret: Signal<Option<Result<Device>>> = use_signal(|| None);
+impl Device {
+    /// The name of the default breakpoint for this device.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Laptop4K => "laptop-4k",
+            Self::LaptopL => "laptop-l",
+            Self::LaptopS => "laptop-s",
+            Self::Tablet => "tablet",
+            Self::MobileL => "mobile-l",
+            Self::MobileM => "mobile-m",
+            Self::MobileS => "mobile-s"
+        }
+    }
 
-    if let Some(w) = inner_w() {
-        match w {
-            Ok(w) => match w {
-                w if w >= 2560.0 => ret.set(Some(Ok(Device::Laptop4K))),
-                w if w >= 1440.0 => ret.set(Some(Ok(Device::LaptopL))),
-                w if w >= 1024.0 => ret.set(Some(Ok(Device::LaptopS))),
-                w if w >= 768.0 => ret.set(Some(Ok(Device::Tablet))),
-                w if w >= 425.0 => ret.set(Some(Ok(Device::MobileL))),
-                w if w >= 375.0 => ret.set(Some(Ok(Device::MobileM))),
-                _ => ret.set(Some(Ok(Device::MobileS)))
-            },
-            Err(e) => ret.set(Some(Err(e)))
-        };
-    } else {
-        ret.set(None);
+    /// The smallest viewport width, in pixels, classified as this device.
+    pub fn min_w(&self) -> f64 {
+        match self {
+            Self::Laptop4K => 2560.0,
+            Self::LaptopL => 1440.0,
+            Self::LaptopS => 1024.0,
+            Self::Tablet => 768.0,
+            Self::MobileL => 425.0,
+            Self::MobileM => 375.0,
+            Self::MobileS => 0.0
+        }
+    }
+
+    pub fn max_w(&self) -> Option<f64> {
+        match self {
+            Self::Laptop4K => None,
+            Self::LaptopL => Some(Self::Laptop4K.min_w()),
+            Self::LaptopS => Some(Self::LaptopL.min_w()),
+            Self::Tablet => Some(Self::LaptopS.min_w()),
+            Self::MobileL => Some(Self::Tablet.min_w()),
+            Self::MobileM => Some(Self::MobileL.min_w()),
+            Self::MobileS => Some(Self::MobileM.min_w())
+        }
     }
+}
+
+/// The breakpoint of the closest `BreakpointsProvider` matching the viewport.
+/// Only notifies when the matching breakpoint changes.
+pub fn use_device() -> Memo<Option<Result<Breakpoint>>> {
+    let inner_w: Signal<_> = use_inner_w();
+    let inner_h: Signal<_> = use_inner_h();
+    let provided: Option<Signal<Breakpoints>> = try_use_context();
 
-    ret
+    use_memo(move || {
+        let breakpoints: Breakpoints = provided
+            .map(|breakpoints| breakpoints.read().to_owned())
+            .unwrap_or_default();
+        let w: f64 = match inner_w() {
+            Some(Ok(w)) => w,
+            Some(Err(e)) => return Some(Err(e)),
+            None => return None
+        };
+        let h: f64 = match inner_h() {
+            Some(Ok(h)) => h,
+            Some(Err(e)) => return Some(Err(e)),
+            None => return None
+        };
+        let breakpoint: Result<Breakpoint> = breakpoints
+            .matching(w, h)
+            .cloned()
+            .ok_or(Error::MissingBreakpoint);
+        Some(breakpoint)
+    })
 }
\ No newline at end of file
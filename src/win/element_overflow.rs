@@ -0,0 +1,71 @@
+use super::*;
+
+/// Whether the content of the element with `id` overflows its box, such as
+/// text that has been clamped or truncated.
+pub fn use_element_overflow(id: &str) -> Signal<Option<Result<bool>>> {
+    let id: String = id.to_owned();
+    let ret: Signal<Option<Result<bool>>> = use_signal(|| None);
+    let drop_token: Signal<Option<DropToken>> = use_signal(|| None);
+
+    let update = {
+        let mut ret: Signal<_> = ret.to_owned();
+        move || {
+            let Some(win) = ::web_sys::window() else {
+                ret.set(Some(Err(Error::MissingWindow)));
+                return
+            };
+            let Some(doc) = win.document() else {
+                ret.set(Some(Err(Error::MissingDocument)));
+                return
+            };
+            let Some(element) = doc.get_element_by_id(&id) else {
+                if ret.peek().is_some() {
+                    ret.set(None);
+                }
+                return
+            };
+            let new: bool = element.scroll_height() > element.client_height() || element.scroll_width() > element.client_width();
+            if *ret.peek() != Some(Ok(new)) {
+                ret.set(Some(Ok(new)));
+            }
+        }
+    };
+
+    use_effect({
+        let mut update = update.to_owned();
+        move || {
+            update();
+        }
+    });
+
+    use_effect({
+        let update = update.to_owned();
+        let mut ret: Signal<_> = ret.to_owned();
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            match on_animation_frame({
+                let mut update = update.to_owned();
+                move || {
+                    update();
+                }
+            }) {
+                Ok(new_drop_token) => {
+                    drop_token.set(None);
+                    drop_token.set(Some(new_drop_token));
+                },
+                Err(e) => {
+                    ret.set(Some(Err(Error::EventListenerAttachmentFailure(e))));
+                }
+            }
+        }
+    });
+
+    use_drop({
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            drop_token.set(None);
+        }
+    });
+
+    ret
+}
\ No newline at end of file
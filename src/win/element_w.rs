@@ -16,11 +16,15 @@ pub fn use_element_w(id: &'static str) -> Signal<Option<Result<f64>>> {
                 return
             };
             let Some(element) = doc.get_element_by_id(id) else {
-                ret.set(None);
+                if ret.peek().is_some() {
+                    ret.set(None);
+                }
                 return
             };
-            let new: f64 = element.get_bounding_client_rect().width();
-            ret.set(Some(Ok(new)));
+            let new: Option<Result<f64>> = Some(Ok(element.get_bounding_client_rect().width()));
+            if *ret.peek() != new {
+                ret.set(new);
+            }
         }
     };
 
@@ -9,6 +9,8 @@ use super::*;
 );
 
 ::modwire::expose!(
+    pub breakpoint
+    pub color_scheme
     pub cursor_client_x
     pub cursor_client_y
     pub cursor_offset_x
@@ -20,10 +22,15 @@ use super::*;
     pub device
     pub element_w
     pub element_h
+    pub element_overflow
     pub inner_h
     pub inner_w
     pub outer_h
     pub outer_w
+    pub prefers_reduced_motion
+    pub scroll_progress
+    pub scroll_top
+    pub visibility
 );
 
 static MOUSE_EVENTS: [&str; 6] = [
@@ -45,6 +52,10 @@ pub enum Error {
     MissingWindow,
     MissingDocument,
     MissingDocumentElement,
+    MissingMediaQueryList,
+    MissingBreakpoint,
+    MissingLocalStorage,
+    StorageAccessFailure(::wasm_bindgen::JsValue),
     UnsupportedConvertionToF64
 }
 
@@ -114,6 +125,31 @@ impl Drop for DropToken {
 }
 
 fn on<A, B>(events: Vec<&'static str>, event_handler: A) -> Result<DropToken>
+where
+    A: 'static,
+    A: FnMut(B),
+    B: 'static,
+    B: ::wasm_bindgen::convert::FromWasmAbi {
+    let win: ::web_sys::Window = ::web_sys::window().ok_or(Error::MissingWindow)?;
+    on_target(win.into(), events, event_handler)
+}
+
+fn on_media_query<T>(query: &str, mut on_change: T) -> Result<(bool, DropToken)>
+where
+    T: 'static,
+    T: FnMut(bool) {
+    let win: ::web_sys::Window = ::web_sys::window().ok_or(Error::MissingWindow)?;
+    let media_query_list: ::web_sys::MediaQueryList = win.match_media(query)
+        .map_err(Error::PropertyAccessFailure)?
+        .ok_or(Error::MissingMediaQueryList)?;
+    let matches: bool = media_query_list.matches();
+    let drop_token: DropToken = on_target(media_query_list.into(), vec!["change"], move |event: ::web_sys::MediaQueryListEvent| {
+        on_change(event.matches());
+    })?;
+    Ok((matches, drop_token))
+}
+
+fn on_target<A, B>(target: ::web_sys::EventTarget, events: Vec<&'static str>, event_handler: A) -> Result<DropToken>
 where
     A: 'static,
     A: FnMut(B),
@@ -122,7 +158,6 @@ where
     use ::wasm_bindgen::prelude::*;
     use ::std::rc;
     use ::std::cell;
-    let win: ::web_sys::Window = ::web_sys::window().ok_or(Error::MissingWindow)?;
     let closure: rc::Rc<_> = {
         let ret: Box<_> = Box::new(event_handler) as Box<dyn FnMut(_)>;
         let ret: Closure<_> = Closure::wrap(ret);
@@ -139,7 +174,7 @@ where
         };
         let closure: &::js_sys::Function = closure.as_ref().unchecked_ref();
         for event in events {
-            win.add_event_listener_with_callback(event, closure).map_err(Error::EventListenerAttachmentFailure)?;
+            target.add_event_listener_with_callback(event, closure).map_err(Error::EventListenerAttachmentFailure)?;
         }
     };
     Ok(DropToken::from_static_dyn_fn_mut_box(Box::new({
@@ -153,7 +188,7 @@ where
                 };
                 let closure: &::js_sys::Function = closure.as_ref().unchecked_ref();
                 for event in events.iter() {
-                    win.remove_event_listener_with_callback(event, closure).expect("");
+                    target.remove_event_listener_with_callback(event, closure).expect("");
                 }
             };
             *closure = None;
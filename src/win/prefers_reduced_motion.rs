@@ -0,0 +1,36 @@
+use super::*;
+
+/// Whether the user has asked the system to minimize non-essential
+/// motion, through the `prefers-reduced-motion` media feature.
+pub fn use_prefers_reduced_motion() -> Signal<Option<Result<bool>>> {
+    let ret: Signal<Option<Result<bool>>> = use_signal(|| None);
+    let drop_token: Signal<Option<DropToken>> = use_signal(|| None);
+
+    use_effect({
+        let mut ret: Signal<_> = ret.to_owned();
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            match on_media_query("(prefers-reduced-motion: reduce)", move |matches| {
+                ret.set(Some(Ok(matches)));
+            }) {
+                Ok((matches, new_drop_token)) => {
+                    ret.set(Some(Ok(matches)));
+                    drop_token.set(None);
+                    drop_token.set(Some(new_drop_token));
+                },
+                Err(e) => {
+                    ret.set(Some(Err(e)));
+                }
+            }
+        }
+    });
+
+    use_drop({
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            drop_token.set(None);
+        }
+    });
+
+    ret
+}
\ No newline at end of file
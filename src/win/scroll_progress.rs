@@ -0,0 +1,77 @@
+use super::*;
+
+/// The vertical scroll progress of the element with `id`, from `0.0` at
+/// the top to `1.0` at the bottom. Elements that do not overflow report `0.0`.
+pub fn use_scroll_progress(id: &'static str) -> Signal<Option<Result<f64>>> {
+    let ret: Signal<Option<Result<f64>>> = use_signal(|| None);
+    let drop_token: Signal<Option<DropToken>> = use_signal(|| None);
+
+    let update = {
+        let mut ret: Signal<_> = ret.to_owned();
+        move || {
+            let Some(win) = ::web_sys::window() else {
+                ret.set(Some(Err(Error::MissingWindow)));
+                return
+            };
+            let Some(doc) = win.document() else {
+                ret.set(Some(Err(Error::MissingDocument)));
+                return
+            };
+            let Some(element) = doc.get_element_by_id(id) else {
+                if ret.peek().is_some() {
+                    ret.set(None);
+                }
+                return
+            };
+            let range: f64 = (element.scroll_height() - element.client_height()) as f64;
+            if range <= 0.0 {
+                if *ret.peek() != Some(Ok(0.0)) {
+                    ret.set(Some(Ok(0.0)));
+                }
+                return
+            }
+            let new: f64 = (element.scroll_top() as f64 / range).clamp(0.0, 1.0);
+            if *ret.peek() != Some(Ok(new)) {
+                ret.set(Some(Ok(new)));
+            }
+        }
+    };
+
+    use_effect({
+        let mut update = update.to_owned();
+        move || {
+            update();
+        }
+    });
+
+    use_effect({
+        let update = update.to_owned();
+        let mut ret: Signal<_> = ret.to_owned();
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            match on_animation_frame({
+                let mut update = update.to_owned();
+                move || {
+                    update();
+                }
+            }) {
+                Ok(new_drop_token) => {
+                    drop_token.set(None);
+                    drop_token.set(Some(new_drop_token));
+                },
+                Err(e) => {
+                    ret.set(Some(Err(Error::EventListenerAttachmentFailure(e))));
+                }
+            }
+        }
+    });
+
+    use_drop({
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            drop_token.set(None);
+        }
+    });
+
+    ret
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use super::*;
+
+/// The fraction of the element with `id` that lies within the viewport's
+/// height, from `0.0` when hidden to `1.0` when fully visible.
+pub fn use_visibility(id: &'static str) -> Signal<Option<Result<f64>>> {
+    let ret: Signal<Option<Result<f64>>> = use_signal(|| None);
+    let drop_token: Signal<Option<DropToken>> = use_signal(|| None);
+
+    let update = {
+        let mut ret: Signal<_> = ret.to_owned();
+        move || {
+            let Some(win) = ::web_sys::window() else {
+                ret.set(Some(Err(Error::MissingWindow)));
+                return
+            };
+            let Some(doc) = win.document() else {
+                ret.set(Some(Err(Error::MissingDocument)));
+                return
+            };
+            let Some(element) = doc.get_element_by_id(id) else {
+                if ret.peek().is_some() {
+                    ret.set(None);
+                }
+                return
+            };
+            let viewport_h: f64 = match win.inner_height() {
+                Ok(viewport_h) => {
+                    let Some(viewport_h) = viewport_h.as_f64() else {
+                        ret.set(Some(Err(Error::UnsupportedConvertionToF64)));
+                        return
+                    };
+                    viewport_h
+                },
+                Err(e) => {
+                    ret.set(Some(Err(Error::PropertyAccessFailure(e))));
+                    return
+                }
+            };
+            let rect: ::web_sys::DomRect = element.get_bounding_client_rect();
+            if rect.height() <= 0.0 {
+                if *ret.peek() != Some(Ok(0.0)) {
+                    ret.set(Some(Ok(0.0)));
+                }
+                return
+            }
+            let visible_h: f64 = rect.bottom().min(viewport_h) - rect.top().max(0.0);
+            let new: f64 = (visible_h / rect.height()).clamp(0.0, 1.0);
+            if *ret.peek() != Some(Ok(new)) {
+                ret.set(Some(Ok(new)));
+            }
+        }
+    };
+
+    use_effect({
+        let mut update = update.to_owned();
+        move || {
+            update();
+        }
+    });
+
+    use_effect({
+        let update = update.to_owned();
+        let mut ret: Signal<_> = ret.to_owned();
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            match on_animation_frame({
+                let mut update = update.to_owned();
+                move || {
+                    update();
+                }
+            }) {
+                Ok(new_drop_token) => {
+                    drop_token.set(None);
+                    drop_token.set(Some(new_drop_token));
+                },
+                Err(e) => {
+                    ret.set(Some(Err(Error::EventListenerAttachmentFailure(e))));
+                }
+            }
+        }
+    });
+
+    use_drop({
+        let mut drop_token: Signal<_> = drop_token.to_owned();
+        move || {
+            drop_token.set(None);
+        }
+    });
+
+    ret
+}
\ No newline at end of file